@@ -4,6 +4,8 @@
 
 SCRIPT_DIR="$(cd "$(dirname "$0")" && pwd)"
 PROJECT_DIR="$(dirname "$SCRIPT_DIR")"
+# Fixed seed so fights and the thief play out the same on every run.
+export ZORK_SEED="${ZORK_SEED:-1}"
 LOG_DIR="${PROJECT_DIR}/smoke-logs"
 TIMEOUT=60
 
@@ -67,9 +69,43 @@ EOF
     fi
 }
 
+run_validate_with_log() {
+    local log_file="$LOG_DIR/validate.log"
+
+    echo "=== Validating worlds ===" | tee -a "$LOG_DIR/smoke-output.txt"
+
+    cd "$PROJECT_DIR"
+
+    if [ ! -f target/release/zork-termux ]; then
+        cargo build --release >> "$log_file" 2>&1
+    fi
+
+    local cmd_exit=0
+    {
+        echo "=== validate ==="
+        echo "Date: $(date -Iseconds)"
+        echo "---"
+        run_with_timeout "$TIMEOUT" ./target/release/zork-termux validate 2>&1
+        cmd_exit=$?
+        echo "---"
+        echo "Exit code: $cmd_exit"
+    } > "$log_file"
+
+    cat "$log_file" >> "$LOG_DIR/smoke-output.txt"
+
+    if [ $cmd_exit -eq 0 ]; then
+        echo "PASS: validate" | tee -a "$LOG_DIR/smoke-output.txt"
+        return 0
+    else
+        echo "FAIL: validate" | tee -a "$LOG_DIR/smoke-output.txt"
+        return 1
+    fi
+}
+
 # Run tests
 FAILED=0
 
+run_validate_with_log || ((FAILED++))
 run_smoke_with_log "1" "English" || ((FAILED++))
 run_smoke_with_log "2" "Italian" || ((FAILED++))
 run_smoke_with_log "3" "Spanish" || ((FAILED++))
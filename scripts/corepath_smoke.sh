@@ -6,6 +6,8 @@ set -u
 
 SCRIPT_DIR="$(cd "$(dirname "$0")" && pwd)"
 PROJECT_DIR="$(dirname "$SCRIPT_DIR")"
+# Fixed seed so fights and the thief play out the same on every run.
+export ZORK_SEED="${ZORK_SEED:-1}"
 TIMEOUT=10
 LOGS_DIR="$PROJECT_DIR/logs/corepath"
 
@@ -84,7 +86,7 @@ run_test "CP19" "EN Use lamp (in living room)" "1" "north\neast\nin\nwest\ntake
 run_test "CP27" "EN Inventory" "1" "open mailbox\ntake leaflet\ninventory" "carrying"
 run_test "CP28" "EN Drop item" "1" "open mailbox\ntake leaflet\ndrop leaflet" "Dropped"
 run_test "CP30" "EN Save game" "1" "save" "saved"
-run_test "CP20" "EN Trap door blocks" "1" "north\neast\nenter\nwest\ndown" "locked"
+run_test "CP20" "EN Trap door blocks" "1" "north\neast\nenter\nwest\ndown" "trap door is closed"
 run_test "CP21" "EN Cellar darkness" "1" "north\neast\nenter\nwest\nopen trap door\ndown" "pitch dark"
 run_test "CP31" "EN Save/restore" "1" "save\nrestore" "restored"
 
@@ -5,6 +5,8 @@
 
 SCRIPT_DIR="$(cd "$(dirname "$0")" && pwd)"
 PROJECT_DIR="$(dirname "$SCRIPT_DIR")"
+# Fixed seed so fights and the thief play out the same on every run.
+export ZORK_SEED="${ZORK_SEED:-1}"
 TIMEOUT=60
 
 FAILED=0
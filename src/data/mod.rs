@@ -1,3 +0,0 @@
-pub mod zork1;
-pub mod zork2;
-pub mod zork3;
@@ -1,144 +0,0 @@
-// ZORK I - The Great Underground Empire
-// Room data extracted from ZIL source
-
-pub const ROOMS: &[RoomData] = &[
-    RoomData {
-        id: "west_of_house",
-        name_en: "West of House",
-        desc_en: "You are standing in an open field west of a white house, with a boarded front door.\nThere is a small mailbox here.",
-        name_it: "A Ovest della Casa",
-        desc_it: "Ti trovi in un campo aperto a ovest di una casa bianca, con la porta d'ingresso chiodata.\nC'è una piccola cassetta postale qui.",
-        name_es: "Al Oeste de la Casa",
-        desc_es: "Estás en un campo abierto al oeste de una casa blanca, con la puerta principal tablada.\nHay un pequeño buzón aquí.",
-        exits: &[("north", "north_of_house"), ("south", "south_of_house")],
-    },
-    RoomData {
-        id: "north_of_house",
-        name_en: "North of House",
-        desc_en: "You are facing the north side of a white house. There is no door here, and all the windows are barred.",
-        name_it: "A Nord della Casa",
-        desc_it: "Sei di fronte al lato nord di una casa bianca. Non c'è porta qui, e tutte le finestre sono sbarrate.",
-        name_es: "Al Norte de la Casa",
-        desc_es: "Estás frente al lado norte de una casa blanca. No hay puerta aquí, y todas las ventanas están enrejadas.",
-        exits: &[("south", "west_of_house"), ("east", "behind_house")],
-    },
-    RoomData {
-        id: "south_of_house",
-        name_en: "South of House",
-        desc_en: "You are facing the south side of a white house. There is no door here, and all the windows are barred.",
-        name_it: "A Sud della Casa",
-        desc_it: "Sei di fronte al lato sud di una casa bianca. Non c'è porta qui, e tutte le finestre sono sbarrate.",
-        name_es: "Al Sur de la Casa",
-        desc_es: "Estás frente al lado sur de una casa blanca. No hay puerta aquí, y todas las ventanas están enrejadas.",
-        exits: &[("north", "west_of_house"), ("east", "behind_house")],
-    },
-    RoomData {
-        id: "behind_house",
-        name_en: "Behind House",
-        desc_en: "You are behind the white house. A path leads into the forest to the east. In one corner of the house there is a small window which is slightly ajar.",
-        name_it: "Dietro la Casa",
-        desc_it: "Sei dietro la casa bianca. Un sentiero porta nella foresta a est. In un angolo c'è una piccola finestra leggermente aperta.",
-        name_es: "Detrás de la Casa",
-        desc_es: "Estás detrás de la casa blanca. Un sendero lleva al bosque al este. En una esquina hay una pequeña ventana ligeramente abierta.",
-        exits: &[("west", "north_of_house"), ("north", "north_of_house"), ("south", "south_of_house")],
-    },
-    RoomData {
-        id: "kitchen",
-        name_en: "Kitchen",
-        desc_en: "You are in the kitchen of the white house. A table seems to have been used recently for the preparation of food.",
-        name_it: "Cucina",
-        desc_it: "Sei nella cucina della casa bianca. Un tavolo sembra essere stato usato di recente per preparare cibo.",
-        name_es: "Cocina",
-        desc_es: "Estás en la cocina de la casa blanca. Una mesa parece haber sido usada recientemente para preparar comida.",
-        exits: &[("west", "living_room"), ("up", "attic"), ("east", "behind_house")],
-    },
-    RoomData {
-        id: "living_room",
-        name_en: "Living Room",
-        desc_en: "You are in the living room. There is a doorway to the east, a wooden door with strange gothic lettering to the north.",
-        name_it: "Soggiorno",
-        desc_it: "Sei nel soggiorno. C'è una porta a est, una porta di legno con strane lettere gotiche a nord.",
-        name_es: "Sala de Estar",
-        desc_es: "Estás en la sala de estar. Hay una puerta al este, una puerta de madera con extrañas letras góticas al norte.",
-        exits: &[("east", "kitchen"), ("north", "strange_door")],
-    },
-    RoomData {
-        id: "attic",
-        name_en: "Attic",
-        desc_en: "This is the attic. The only exit is a stairway leading down. A large coil of rope is lying in the corner.",
-        name_it: "Soffitta",
-        desc_it: "Questa è la soffitta. L'unica uscita è una scala che scende. Una grande corda è nell'angolo.",
-        name_es: "Ático",
-        desc_es: "Este es el ático. La única salida es una escalera que baja. Una gran cuerda está en la esquina.",
-        exits: &[("down", "kitchen")],
-    },
-];
-
-pub struct RoomData {
-    pub id: &'static str,
-    pub name_en: &'static str,
-    pub desc_en: &'static str,
-    pub name_it: &'static str,
-    pub desc_it: &'static str,
-    pub name_es: &'static str,
-    pub desc_es: &'static str,
-    pub exits: &'static [(&'static str, &'static str)],
-}
-
-pub struct ObjectData {
-    pub id: &'static str,
-    pub name_en: &'static str,
-    pub name_it: &'static str,
-    pub name_es: &'static str,
-    pub takeable: bool,
-    pub openable: bool,
-    pub open: bool,
-}
-
-pub const OBJECTS: &[ObjectData] = &[
-    ObjectData {
-        id: "mailbox",
-        name_en: "mailbox",
-        name_it: "cassetta postale",
-        name_es: "buzón",
-        takeable: false,
-        openable: true,
-        open: false,
-    },
-    ObjectData {
-        id: "leaflet",
-        name_en: "leaflet",
-        name_it: "volantino",
-        name_es: "folleto",
-        takeable: true,
-        openable: false,
-        open: false,
-    },
-    ObjectData {
-        id: "rope",
-        name_en: "rope",
-        name_it: "corda",
-        name_es: "cuerda",
-        takeable: true,
-        openable: false,
-        open: false,
-    },
-    ObjectData {
-        id: "knife",
-        name_en: "nasty knife",
-        name_it: "coltello",
-        name_es: "cuchillo",
-        takeable: true,
-        openable: false,
-        open: false,
-    },
-    ObjectData {
-        id: "window",
-        name_en: "small window",
-        name_it: "piccola finestra",
-        name_es: "pequeña ventana",
-        takeable: false,
-        openable: true,
-        open: true,
-    },
-];
@@ -1,4 +0,0 @@
-// ZORK II - The Wizard of Frobozz
-// Placeholder for future implementation
-
-pub const START_ROOM: &str = "barrow";
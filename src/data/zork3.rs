@@ -1,4 +0,0 @@
-// ZORK III - The Dungeon Master
-// Placeholder for future implementation
-
-pub const START_ROOM: &str = "landing";
@@ -1,52 +1,196 @@
-use crate::game::state::GameState;
-use crate::game::world::{CreatureState, World};
-use crate::i18n::{I18n, Language};
+use crate::game::ending;
+use crate::game::light;
+use crate::game::npc;
+use crate::game::save::{self, SaveError, SaveFile};
+use crate::game::score;
+use crate::game::state::{GameState, MAX_HEALTH};
+use crate::game::world::{Barrier, CreatureState, LightKind, World};
+use crate::i18n::{I18n, Language, coverage};
 use crate::logging;
 use crate::parser::Command;
-use crate::parser::Verb;
+use crate::parser::{Preposition, Selection, Verb, Words};
+
+/// What a command did, as far as the caller needs to know. A failed command
+/// (unknown object, blocked exit, missing noun) stops a chained input; the
+/// referents are the objects or creatures it acted on, which `it` / `them`
+/// refer to next.
+#[derive(Debug, Clone, Default)]
+pub struct Outcome {
+    pub failed: bool,
+    pub referents: Vec<String>,
+    pub ambiguity: Option<Ambiguity>,
+}
+
+/// A noun phrase that matched several things equally well. The player was
+/// asked which one; `Game` keeps the command to retry with the answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ambiguity {
+    pub phrase: String,
+    pub candidates: Vec<String>,
+}
+
+impl Outcome {
+    pub fn done() -> Self {
+        Outcome::default()
+    }
+
+    pub fn failed() -> Self {
+        Outcome {
+            failed: true,
+            ..Outcome::default()
+        }
+    }
 
-pub fn execute(state: &mut GameState, world: &mut World, cmd: Command, i18n: &I18n) {
+    pub fn about(referents: Vec<String>) -> Self {
+        Outcome {
+            referents,
+            ..Outcome::default()
+        }
+    }
+}
+
+pub fn execute(state: &mut GameState, world: &mut World, cmd: Command, i18n: &I18n) -> Outcome {
     state.moves += 1;
     let ui = i18n.ui();
     let verb_dbg = format!("{:?}", cmd.verb);
-    let object_dbg = cmd.object.clone().unwrap_or_default();
+    let direct_dbg = cmd.direct.clone().unwrap_or_default();
+    let indirect_dbg = cmd.indirect.clone().unwrap_or_default();
     logging::info(format!(
-        "command.exec room={} moves={} verb={} object={}",
-        state.current_room, state.moves, verb_dbg, object_dbg
+        "command.exec room={} moves={} verb={} direct={} prep={:?} indirect={}",
+        state.current_room, state.moves, verb_dbg, direct_dbg, cmd.preposition, indirect_dbg
     ));
 
-    match cmd.verb {
-        Verb::Look => cmd_look(state, world, i18n),
-        Verb::Inventory => cmd_inventory(state, world, i18n),
-        Verb::North | Verb::South | Verb::East | Verb::West | Verb::Up | Verb::Down => {
-            cmd_move(state, world, &cmd.verb, i18n);
-        }
-        Verb::Take => cmd_take(state, world, cmd.object.as_deref(), i18n),
-        Verb::Drop => cmd_drop(state, world, cmd.object.as_deref(), i18n),
-        Verb::Examine => cmd_examine(state, world, cmd.object.as_deref(), i18n),
-        Verb::Open => cmd_open(state, world, cmd.object.as_deref(), i18n),
-        Verb::Close => cmd_close(state, world, cmd.object.as_deref(), i18n),
-        Verb::Read => cmd_read(state, world, cmd.object.as_deref(), i18n),
-        Verb::Use => cmd_use(state, cmd.object.as_deref(), i18n),
-        Verb::Help => println!("\n{}", i18n.help_text()),
-        Verb::Score => println!("\n{}", i18n.score_text(state.score, state.moves)),
-        Verb::Save => match state.save(1) {
-            Ok(()) => {
-                logging::info("save.ok slot=1");
-                println!("\n{}", ui.game_saved);
-            }
-            Err(e) => {
-                logging::error(format!("save.failed slot=1 error={}", e));
-                println!("\n{}: {}", ui.save_failed, e);
-            }
+    let takes_time = cmd.verb.takes_time();
+
+    let outcome = match cmd.verb {
+        Verb::Look => {
+            cmd_look(state, world, i18n);
+            Outcome::done()
+        }
+        Verb::Inventory => {
+            cmd_inventory(state, world, i18n);
+            Outcome::done()
+        }
+        Verb::North
+        | Verb::South
+        | Verb::East
+        | Verb::West
+        | Verb::NorthEast
+        | Verb::NorthWest
+        | Verb::SouthEast
+        | Verb::SouthWest
+        | Verb::Up
+        | Verb::Down => cmd_move(state, world, &cmd.verb, i18n),
+        Verb::Take => match &cmd.selection {
+            Some(selection) => cmd_take_many(state, world, selection, i18n),
+            None => cmd_take(state, world, cmd.direct.as_deref(), i18n),
         },
-        Verb::Restore => cmd_restore(state, world, i18n),
-        Verb::Attack => cmd_attack(state, world, cmd.object.as_deref(), i18n),
-        Verb::Put => cmd_put(state, world, cmd.object.as_deref(), i18n),
-        Verb::Enter => cmd_enter(state, world, cmd.object.as_deref(), i18n),
+        Verb::Drop => match &cmd.selection {
+            Some(selection) => cmd_drop_many(state, world, selection, i18n),
+            None => cmd_drop(state, world, cmd.direct.as_deref(), i18n),
+        },
+        Verb::Examine => cmd_examine(state, world, cmd.direct.as_deref(), i18n),
+        Verb::Open => cmd_open(state, world, cmd.direct.as_deref(), i18n),
+        Verb::Close => cmd_close(state, world, cmd.direct.as_deref(), i18n),
+        Verb::Read => cmd_read(state, world, cmd.direct.as_deref(), i18n),
+        Verb::Use => cmd_use(state, world, cmd.direct.as_deref(), i18n),
+        Verb::Help => {
+            println!("\n{}", i18n.help_text());
+            Outcome::done()
+        }
+        Verb::Score => {
+            println!(
+                "\n{}",
+                i18n.score_text(state.score, world.max_score, state.moves)
+            );
+            Outcome::done()
+        }
+        Verb::Save => cmd_save(state, world, cmd.direct.as_deref(), i18n),
+        Verb::Restore => cmd_restore(state, world, cmd.direct.as_deref(), i18n),
+        Verb::Saves => cmd_saves(state, world, i18n),
+        Verb::Delete => cmd_delete(state, cmd.direct.as_deref(), i18n),
+        // Undo needs the turn history owned by `Game`; reaching here means none.
+        Verb::Undo => {
+            println!("\n{}", ui.cant_undo);
+            Outcome::failed()
+        }
+        Verb::Attack => {
+            let weapon = match cmd.preposition {
+                Some(Preposition::With) => cmd.indirect.as_deref(),
+                _ => None,
+            };
+            cmd_attack(state, world, cmd.direct.as_deref(), weapon, i18n)
+        }
+        Verb::Put => {
+            let container = match cmd.preposition {
+                Some(Preposition::In | Preposition::On) => cmd.indirect.as_deref(),
+                _ => None,
+            };
+            match &cmd.selection {
+                Some(selection) => cmd_put_many(state, world, selection, container, i18n),
+                None => cmd_put(state, world, cmd.direct.as_deref(), container, i18n),
+            }
+        }
+        Verb::Give => {
+            let recipient = match cmd.preposition {
+                Some(Preposition::To) => cmd.indirect.as_deref(),
+                _ => None,
+            };
+            cmd_give(state, world, cmd.direct.as_deref(), recipient, i18n)
+        }
+        Verb::Enter => cmd_enter(state, world, cmd.direct.as_deref(), i18n),
+        Verb::Lock | Verb::Unlock => {
+            let key = match cmd.preposition {
+                Some(Preposition::With) => cmd.indirect.as_deref(),
+                _ => None,
+            };
+            let lock = matches!(cmd.verb, Verb::Lock);
+            cmd_lock(state, world, cmd.direct.as_deref(), key, lock, i18n)
+        }
+        Verb::Eat => cmd_consume(state, world, cmd.direct.as_deref(), false, i18n),
+        Verb::Drink => cmd_consume(state, world, cmd.direct.as_deref(), true, i18n),
+        Verb::Climb => cmd_climb(state, world, cmd.direct.as_deref(), i18n),
+        Verb::Tie => {
+            let target = match cmd.preposition {
+                Some(Preposition::To | Preposition::On) => cmd.indirect.as_deref(),
+                _ => None,
+            };
+            cmd_tie(state, world, cmd.direct.as_deref(), target, i18n)
+        }
+        Verb::Untie => cmd_untie(state, world, cmd.direct.as_deref(), i18n),
+        Verb::Light => {
+            let flame = match cmd.preposition {
+                Some(Preposition::With) => cmd.indirect.as_deref(),
+                _ => None,
+            };
+            cmd_light(state, world, cmd.direct.as_deref(), flame, i18n)
+        }
+        Verb::Extinguish => cmd_extinguish(state, world, cmd.direct.as_deref(), i18n),
         Verb::Unknown(v) => {
             logging::warn(format!("command.unknown raw={}", v));
             println!("\n{}", i18n.format(&ui.unknown_command, &[("cmd", &v)]));
+            Outcome::failed()
+        }
+    };
+
+    if takes_time {
+        run_timers(state, world, i18n);
+        light::lurking_grue(state, world, i18n);
+    }
+    ending::update(state, world, i18n);
+
+    outcome
+}
+
+/// Advances the scheduler one turn and runs whatever fired.
+fn run_timers(state: &mut GameState, world: &mut World, i18n: &I18n) {
+    for id in state.timers.tick() {
+        match id.as_str() {
+            light::DAEMON => light::burn(state, world, i18n),
+            light::MATCH_FUSE => light::match_burns_out(state, world, i18n),
+            npc::THIEF => npc::thief_daemon(state, world, i18n),
+            npc::THIEF_STASH => npc::thief_stash(state, world),
+            other => logging::warn(format!("timer.unknown id={}", other)),
         }
     }
 }
@@ -55,7 +199,7 @@ fn cmd_look(state: &GameState, world: &World, i18n: &I18n) {
     let room_id = &state.current_room;
     let room = world.get_room(room_id);
 
-    if room.is_dark && !has_light(state, world) {
+    if room.is_dark && !light::has_light(state, world) {
         println!("\n{}", i18n.ui().darkness);
         return;
     }
@@ -104,56 +248,66 @@ fn cmd_inventory(state: &GameState, world: &World, i18n: &I18n) {
     }
 }
 
-fn cmd_move(state: &mut GameState, world: &mut World, verb: &Verb, i18n: &I18n) {
+fn cmd_move(state: &mut GameState, world: &mut World, verb: &Verb, i18n: &I18n) -> Outcome {
     let direction = match verb {
         Verb::North => "north",
         Verb::South => "south",
         Verb::East => "east",
         Verb::West => "west",
+        Verb::NorthEast => "ne",
+        Verb::NorthWest => "nw",
+        Verb::SouthEast => "se",
+        Verb::SouthWest => "sw",
         Verb::Up => "up",
         Verb::Down => "down",
-        _ => return,
+        _ => return Outcome::failed(),
     };
 
     let current_room = state.current_room.clone();
     let room = world.get_room(&current_room);
 
     if let Some(new_room) = room.exits.get(direction) {
-        if let Some(blocker) = world.blocking_creature(&current_room, new_room) {
-            logging::warn(format!(
-                "move.blocked from={} to={} by={}",
-                current_room, new_room, blocker.id
-            ));
-            if let Some(creature_trans) = i18n.creature(&blocker.id) {
-                println!("\n{}", creature_trans.description);
-            } else {
-                println!("\n{}", i18n.ui().cant_go);
+        match world.exit_barrier(&current_room, direction) {
+            Some(Barrier::Creature(blocker)) => {
+                logging::warn(format!(
+                    "move.blocked from={} to={} by={}",
+                    current_room, new_room, blocker.id
+                ));
+                if let Some(creature_trans) = i18n.creature(&blocker.id) {
+                    println!("\n{}", creature_trans.description);
+                } else {
+                    println!("\n{}", i18n.ui().cant_go);
+                }
+                return Outcome::failed();
             }
-            return;
-        }
-
-        if let Some(blocking_obj_id) = room.blocked_exits.get(direction) {
-            if let Some(obj) = world.get_object(blocking_obj_id) {
-                if obj.is_openable && !obj.is_open {
-                    logging::warn(format!(
-                        "move.blocked_by_object from={} to={} by={}",
-                        current_room, new_room, blocking_obj_id
-                    ));
+            Some(Barrier::Object(obj)) => {
+                logging::warn(format!(
+                    "move.blocked_by_object from={} to={} by={} locked={}",
+                    current_room, new_room, obj.id, obj.is_locked
+                ));
+                if obj.is_locked {
                     println!("\n{}", i18n.ui().locked);
-                    return;
+                } else if obj.tieable {
+                    println!("\n{}", i18n.ui().cant_go);
+                } else {
+                    let name = object_display_name(world, i18n, &obj.id);
+                    println!("\n{}", i18n.format(&i18n.ui().is_closed, &[("obj", &name)]));
                 }
+                return Outcome::failed();
             }
+            None => {}
         }
 
         let new_room_obj = world.get_room(new_room);
-        let is_dark_move = new_room_obj.is_dark && !has_light(state, world);
+        let is_dark_move = new_room_obj.is_dark && !light::has_light(state, world);
 
         state.move_to(new_room);
+        score::enter_room(state, world, new_room);
 
         if is_dark_move {
             println!("\n{}", i18n.ui().darkness);
             logging::info(format!("move.dark from={} to={}", current_room, new_room));
-            return;
+            return Outcome::done();
         }
 
         let room_id = &state.current_room;
@@ -165,52 +319,148 @@ fn cmd_move(state: &mut GameState, world: &mut World, verb: &Verb, i18n: &I18n)
             println!("\n{}\n", display_room.name);
         }
         logging::info(format!("move.ok from={} to={}", current_room, new_room));
+        Outcome::done()
     } else {
         logging::warn(format!(
             "move.invalid from={} direction={}",
             current_room, direction
         ));
         println!("\n{}", i18n.ui().cant_go);
+        Outcome::failed()
     }
 }
 
-fn has_light(state: &GameState, world: &World) -> bool {
-    if state.lamp_lit {
-        return true;
-    }
-    for item_id in &state.inventory {
-        if let Some(obj) = world.get_object(item_id) {
-            if obj.is_lit {
-                return true;
+fn cmd_take(
+    state: &mut GameState,
+    world: &mut World,
+    object: Option<&str>,
+    i18n: &I18n,
+) -> Outcome {
+    let ui = i18n.ui();
+
+    match object {
+        Some(obj_name) => match find_object_by_name(world, &state.current_room, i18n, obj_name) {
+            Ok(id) => {
+                if !take_object(state, world, &id) {
+                    println!("\n{}", ui.cant_take);
+                    return Outcome::failed();
+                }
+                println!("\n{}", ui.taken);
+                Outcome::about(vec![id])
             }
+            Err(miss) => report_miss(world, i18n, obj_name, miss, &ui.dont_see),
+        },
+        None => {
+            println!("\n{}", ui.take_what);
+            Outcome::failed()
         }
     }
-    false
 }
 
-fn cmd_take(state: &mut GameState, world: &mut World, object: Option<&str>, i18n: &I18n) {
+/// `take all [except ...]` / `take sword and lamp`: one result line per
+/// object. `all` only considers takeable objects lying in the room.
+fn cmd_take_many(
+    state: &mut GameState,
+    world: &mut World,
+    selection: &Selection,
+    i18n: &I18n,
+) -> Outcome {
     let ui = i18n.ui();
+    let room_id = state.current_room.clone();
 
-    match object {
-        Some(obj_name) => {
-            let obj_id = find_object_by_name(world, &state.current_room, i18n, obj_name);
-
-            if let Some(id) = obj_id {
-                if let Some(obj) = world.get_object(&id) {
-                    if !obj.takeable {
-                        println!("\n{}", ui.cant_take);
-                        return;
-                    }
+    let mut pool: Vec<String> = world
+        .objects_in_room(&room_id)
+        .into_iter()
+        .filter(|obj| obj.takeable)
+        .map(|obj| obj.id.clone())
+        .collect();
+    pool.sort();
+
+    let targets = resolve_selection(world, i18n, selection, &pool, |name| {
+        find_object_in_reach(state, world, i18n, name)
+    });
+
+    if targets.is_empty() {
+        println!("\n{}", ui.nothing_to_take);
+        return Outcome::failed();
+    }
+
+    println!();
+    let mut taken = Vec::new();
+    for target in targets {
+        match target {
+            Ok(id) => {
+                let name = object_display_name(world, i18n, &id);
+                if state.has_item(&id) {
+                    taken.push(id.clone());
+                    println!("{}: {}", name, ui.already_have);
+                } else if take_object(state, world, &id) {
+                    taken.push(id.clone());
+                    println!("{}: {}", name, ui.taken);
+                } else {
+                    println!("{}: {}", name, ui.cant_take);
                 }
-                remove_from_container(world, &id);
-                world.move_object(&id, "inventory");
-                state.add_to_inventory(id.clone());
-                println!("\n{}", ui.taken);
-            } else {
-                println!("\n{}", ui.dont_see);
+            }
+            Err((name, miss)) => {
+                println!("{}: {}", name, miss_text(world, i18n, &miss, &ui.dont_see))
             }
         }
-        None => println!("\n{}", ui.take_what),
+    }
+
+    logging::info(format!(
+        "take.many room={} taken={}",
+        room_id,
+        taken.join(",")
+    ));
+    if taken.is_empty() {
+        Outcome::failed()
+    } else {
+        Outcome::about(taken)
+    }
+}
+
+/// Moves a takeable object into the inventory. False when it can't be taken.
+fn take_object(state: &mut GameState, world: &mut World, object_id: &str) -> bool {
+    if world
+        .get_object(object_id)
+        .is_some_and(|obj| !obj.takeable || obj.tied_to.is_some())
+    {
+        return false;
+    }
+    if let Some(from) = world.object_location(object_id).map(str::to_string) {
+        score::withdraw(state, world, object_id, &from);
+    }
+    remove_from_container(world, object_id);
+    world.move_object(object_id, "inventory");
+    state.add_to_inventory(object_id.to_string());
+    score::touch(state, world, object_id);
+    true
+}
+
+/// Expands a selection into object ids: `all` draws from `pool` minus the
+/// exceptions, explicit items go through `resolve`. Names that don't pick
+/// out one object come back with the reason so the caller can report them.
+fn resolve_selection(
+    world: &World,
+    i18n: &I18n,
+    selection: &Selection,
+    pool: &[String],
+    resolve: impl Fn(&str) -> Result<String, Miss>,
+) -> Vec<Result<String, (String, Miss)>> {
+    match selection {
+        Selection::All { except } => pool
+            .iter()
+            .filter(|id| {
+                !except
+                    .iter()
+                    .any(|name| object_matches_input(world, i18n, id, name))
+            })
+            .map(|id| Ok(id.clone()))
+            .collect(),
+        Selection::Items(names) => names
+            .iter()
+            .map(|name| resolve(name).map_err(|miss| (name.clone(), miss)))
+            .collect(),
     }
 }
 
@@ -220,279 +470,840 @@ fn remove_from_container(world: &mut World, object_id: &str) {
     }
 }
 
-fn cmd_drop(state: &mut GameState, world: &mut World, object: Option<&str>, i18n: &I18n) {
+fn cmd_drop(
+    state: &mut GameState,
+    world: &mut World,
+    object: Option<&str>,
+    i18n: &I18n,
+) -> Outcome {
     let ui = i18n.ui();
 
     match object {
-        Some(obj_name) => {
-            let obj_id = find_inventory_object_by_name(state, world, i18n, obj_name);
-
-            if let Some(id) = obj_id {
-                world.move_object(&id, &state.current_room);
-                state.remove_from_inventory(&id);
+        Some(obj_name) => match find_inventory_object_by_name(state, world, i18n, obj_name) {
+            Ok(id) => {
+                drop_object(state, world, &id);
                 println!("\n{}", ui.dropped);
-            } else {
-                println!("\n{}", ui.not_holding);
+                Outcome::about(vec![id])
             }
+            Err(miss) => report_miss(world, i18n, obj_name, miss, &ui.not_holding),
+        },
+        None => {
+            println!("\n{}", ui.drop_what);
+            Outcome::failed()
         }
-        None => println!("\n{}", ui.drop_what),
     }
 }
 
-fn cmd_examine(state: &GameState, world: &World, object: Option<&str>, i18n: &I18n) {
+/// `drop all [except ...]` / `drop sword and lamp`.
+fn cmd_drop_many(
+    state: &mut GameState,
+    world: &mut World,
+    selection: &Selection,
+    i18n: &I18n,
+) -> Outcome {
     let ui = i18n.ui();
+    let pool = state.inventory.clone();
 
-    match object {
-        Some(obj_name) => {
-            let obj_id = find_object_by_name(world, &state.current_room, i18n, obj_name)
-                .or_else(|| find_inventory_object_by_name(state, world, i18n, obj_name));
+    let targets = resolve_selection(world, i18n, selection, &pool, |name| {
+        find_inventory_object_by_name(state, world, i18n, name)
+    });
+
+    if targets.is_empty() {
+        println!("\n{}", ui.empty_handed);
+        return Outcome::failed();
+    }
+
+    println!();
+    let mut dropped = Vec::new();
+    for target in targets {
+        match target {
+            Ok(id) if state.has_item(&id) => {
+                drop_object(state, world, &id);
+                dropped.push(id.clone());
+                println!("{}: {}", object_display_name(world, i18n, &id), ui.dropped);
+            }
+            Ok(id) => println!(
+                "{}: {}",
+                object_display_name(world, i18n, &id),
+                ui.not_holding
+            ),
+            Err((name, miss)) => println!(
+                "{}: {}",
+                name,
+                miss_text(world, i18n, &miss, &ui.not_holding)
+            ),
+        }
+    }
+
+    logging::info(format!(
+        "drop.many room={} dropped={}",
+        state.current_room,
+        dropped.join(",")
+    ));
+    if dropped.is_empty() {
+        Outcome::failed()
+    } else {
+        Outcome::about(dropped)
+    }
+}
+
+fn drop_object(state: &mut GameState, world: &mut World, object_id: &str) {
+    world.move_object(object_id, &state.current_room);
+    state.remove_from_inventory(object_id);
+}
+
+fn cmd_examine(state: &GameState, world: &World, object: Option<&str>, i18n: &I18n) -> Outcome {
+    let ui = i18n.ui();
 
-            if let Some(id) = obj_id {
+    match object {
+        Some(obj_name) => match find_object_in_reach(state, world, i18n, obj_name) {
+            Ok(id) => {
                 if let Some(obj_trans) = i18n.object(&id) {
                     println!("\n{}", obj_trans.description);
                 } else if let Some(obj) = world.get_object(&id) {
                     println!("\n{}", obj.name);
                 }
-            } else {
-                println!("\n{}", ui.dont_see);
+                Outcome::about(vec![id])
             }
+            Err(miss) => report_miss(world, i18n, obj_name, miss, &ui.dont_see),
+        },
+        None => {
+            println!("\n{}", ui.examine_what);
+            Outcome::failed()
         }
-        None => println!("\n{}", ui.examine_what),
     }
 }
 
-fn cmd_open(state: &GameState, world: &mut World, object: Option<&str>, i18n: &I18n) {
+fn cmd_open(state: &GameState, world: &mut World, object: Option<&str>, i18n: &I18n) -> Outcome {
     let ui = i18n.ui();
 
-    match object {
-        Some(obj_name) => {
-            let obj_id = find_object_by_name(world, &state.current_room, i18n, obj_name);
-
-            if let Some(id) = obj_id {
-                if let Some(obj) = world.get_object_mut(&id) {
-                    if !obj.is_openable {
-                        println!("\n{}", ui.cant_open);
-                    } else if obj.is_open {
-                        println!("\n{}", ui.already_open);
-                    } else {
-                        obj.is_open = true;
-                        println!("\n{}", ui.opened);
-                        let contents = obj.contents.clone();
-                        if !contents.is_empty() {
-                            println!("\n{}", ui.contains);
-                            for content_id in &contents {
-                                println!("  - {}", object_display_name(world, i18n, content_id));
-                            }
-                        }
-                    }
-                }
-            } else {
-                println!("\n{}", ui.dont_see);
+    let Some(obj_name) = object else {
+        println!("\n{}", ui.open_what);
+        return Outcome::failed();
+    };
+
+    let id = match find_object_in_reach(state, world, i18n, obj_name) {
+        Ok(id) => id,
+        Err(miss) => return report_miss(world, i18n, obj_name, miss, &ui.dont_see),
+    };
+
+    let Some(obj) = world.get_object_mut(&id) else {
+        println!("\n{}", ui.dont_see);
+        return Outcome::failed();
+    };
+    if !obj.is_openable {
+        println!("\n{}", ui.cant_open);
+        return Outcome::failed();
+    }
+    if obj.is_locked {
+        println!("\n{}", ui.locked);
+        return Outcome::failed();
+    }
+    if obj.is_open {
+        println!("\n{}", ui.already_open);
+    } else {
+        obj.is_open = true;
+        println!("\n{}", ui.opened);
+        let contents = obj.contents.clone();
+        if !contents.is_empty() {
+            println!("\n{}", ui.contains);
+            for content_id in &contents {
+                println!("  - {}", object_display_name(world, i18n, content_id));
             }
         }
-        None => println!("\n{}", ui.open_what),
     }
+    Outcome::about(vec![id])
 }
 
-fn cmd_close(state: &GameState, world: &mut World, object: Option<&str>, i18n: &I18n) {
+/// Locks or unlocks `object` with `key`. Without a `with` phrase the key is
+/// taken from the inventory when the player carries it.
+fn cmd_lock(
+    state: &GameState,
+    world: &mut World,
+    object: Option<&str>,
+    key: Option<&str>,
+    lock: bool,
+    i18n: &I18n,
+) -> Outcome {
     let ui = i18n.ui();
 
-    match object {
-        Some(obj_name) => {
-            let obj_id = find_object_by_name(world, &state.current_room, i18n, obj_name);
-
-            if let Some(id) = obj_id {
-                if let Some(obj) = world.get_object_mut(&id) {
-                    if !obj.is_openable {
-                        println!("\n{}", ui.cant_close);
-                    } else if !obj.is_open {
-                        println!("\n{}", ui.already_closed);
-                    } else {
-                        obj.is_open = false;
-                        println!("\n{}", ui.closed);
-                    }
-                }
-            } else {
-                println!("\n{}", ui.dont_see);
-            }
+    let Some(obj_name) = object else {
+        println!("\n{}", if lock { &ui.lock_what } else { &ui.unlock_what });
+        return Outcome::failed();
+    };
+
+    let id = match find_object_by_name(world, &state.current_room, i18n, obj_name) {
+        Ok(id) => id,
+        Err(miss) => return report_miss(world, i18n, obj_name, miss, &ui.dont_see),
+    };
+
+    let Some(needed) = world.get_object(&id).and_then(|obj| obj.key.clone()) else {
+        println!("\n{}", ui.cant_lock);
+        return Outcome::failed();
+    };
+
+    let key_id = match key {
+        Some(key_name) => match find_inventory_object_by_name(state, world, i18n, key_name) {
+            Ok(key_id) => key_id,
+            Err(miss) => return report_miss(world, i18n, key_name, miss, &ui.not_holding),
+        },
+        None if state.inventory.contains(&needed) => needed.clone(),
+        None => {
+            println!("\n{}", ui.need_key);
+            return Outcome::failed();
+        }
+    };
+
+    if key_id != needed {
+        logging::info(format!("lock.wrong_key object={} key={}", id, key_id));
+        println!("\n{}", ui.wrong_key);
+        return Outcome::failed();
+    }
+
+    let Some(obj) = world.get_object_mut(&id) else {
+        println!("\n{}", ui.dont_see);
+        return Outcome::failed();
+    };
+    let message = match (lock, obj.is_locked) {
+        (true, true) => &ui.already_locked,
+        (false, false) => &ui.not_locked,
+        (true, false) if obj.is_open => &ui.close_first,
+        (true, false) => {
+            obj.is_locked = true;
+            &ui.locked_done
         }
-        None => println!("\n{}", ui.close_what),
+        (false, true) => {
+            obj.is_locked = false;
+            &ui.unlocked
+        }
+    };
+    logging::info(format!(
+        "lock.{} object={} key={} locked={}",
+        if lock { "lock" } else { "unlock" },
+        id,
+        key_id,
+        obj.is_locked
+    ));
+    println!("\n{}", message);
+    Outcome::about(vec![id])
+}
+
+fn cmd_close(state: &GameState, world: &mut World, object: Option<&str>, i18n: &I18n) -> Outcome {
+    let ui = i18n.ui();
+
+    let Some(obj_name) = object else {
+        println!("\n{}", ui.close_what);
+        return Outcome::failed();
+    };
+
+    let id = match find_object_in_reach(state, world, i18n, obj_name) {
+        Ok(id) => id,
+        Err(miss) => return report_miss(world, i18n, obj_name, miss, &ui.dont_see),
+    };
+
+    let Some(obj) = world.get_object_mut(&id) else {
+        println!("\n{}", ui.dont_see);
+        return Outcome::failed();
+    };
+    if !obj.is_openable {
+        println!("\n{}", ui.cant_close);
+        return Outcome::failed();
     }
+    if !obj.is_open {
+        println!("\n{}", ui.already_closed);
+    } else {
+        obj.is_open = false;
+        println!("\n{}", ui.closed);
+    }
+    Outcome::about(vec![id])
 }
 
-fn cmd_read(state: &GameState, world: &World, object: Option<&str>, i18n: &I18n) {
+fn cmd_read(state: &GameState, world: &World, object: Option<&str>, i18n: &I18n) -> Outcome {
     let ui = i18n.ui();
 
     match object {
         Some(obj_name) => {
-            let obj_id = find_object_by_name(world, &state.current_room, i18n, obj_name)
-                .or_else(|| find_inventory_object_by_name(state, world, i18n, obj_name));
-
-            if let Some(id) = obj_id {
-                if let Some(obj_trans) = i18n.object(&id) {
-                    if let Some(read_text) = &obj_trans.read {
-                        println!("\n{}", read_text);
-                    } else {
-                        println!("\n{}", ui.cant_read);
-                    }
-                } else {
+            let id = match find_object_in_reach(state, world, i18n, obj_name) {
+                Ok(id) => id,
+                Err(miss) => return report_miss(world, i18n, obj_name, miss, &ui.dont_see),
+            };
+
+            match i18n.object(&id).and_then(|t| t.read.as_ref()) {
+                Some(read_text) => {
+                    println!("\n{}", read_text);
+                    Outcome::about(vec![id])
+                }
+                None => {
                     println!("\n{}", ui.cant_read);
+                    Outcome::failed()
                 }
-            } else {
-                println!("\n{}", ui.dont_see);
             }
         }
-        None => println!("\n{}", ui.cant_read),
+        None => {
+            println!("\n{}", ui.cant_read);
+            Outcome::failed()
+        }
     }
 }
 
-fn cmd_use(state: &mut GameState, object: Option<&str>, i18n: &I18n) {
+/// `use` on a light source switches it; nothing else has a use yet.
+fn cmd_use(state: &mut GameState, world: &mut World, object: Option<&str>, i18n: &I18n) -> Outcome {
     let ui = i18n.ui();
 
-    match object {
-        Some(obj_name) => {
-            let normalized = normalize_text(obj_name);
-            let known = ["lamp", "lantern", "lanterna", "linterna"];
+    let Some(obj_name) = object else {
+        println!("\n{}", i18n.ui().use_what);
+        return Outcome::failed();
+    };
 
-            if known.iter().any(|item| *item == normalized) {
-                state.lamp_lit = !state.lamp_lit;
-                if state.lamp_lit {
-                    println!("\n{}", ui.lamp_on);
-                } else {
-                    println!("\n{}", ui.lamp_off);
-                }
-            } else {
-                println!("\n{}", ui.unknown_command.replace("{cmd}", obj_name));
-            }
+    let id = match find_object_in_reach(state, world, i18n, obj_name) {
+        Ok(id) => id,
+        Err(miss) => return report_miss(world, i18n, obj_name, miss, &ui.dont_see),
+    };
+
+    match world.get_object(&id) {
+        Some(obj) if obj.light.is_some() && obj.is_lit => {
+            cmd_extinguish(state, world, Some(obj_name), i18n)
+        }
+        Some(obj) if obj.light.is_some() => cmd_light(state, world, Some(obj_name), None, i18n),
+        _ => {
+            println!(
+                "\n{}",
+                i18n.format(&ui.unknown_command, &[("cmd", obj_name)])
+            );
+            Outcome::failed()
         }
-        None => println!("\n{}", use_prompt(i18n.language())),
     }
 }
 
-fn cmd_attack(state: &mut GameState, world: &mut World, object: Option<&str>, i18n: &I18n) {
+/// Lights a lamp, candles or a match. Candles need a flame: the `with`
+/// object, or any burning match or candle the player can reach.
+fn cmd_light(
+    state: &mut GameState,
+    world: &mut World,
+    object: Option<&str>,
+    flame: Option<&str>,
+    i18n: &I18n,
+) -> Outcome {
     let ui = i18n.ui();
 
-    let Some(target_input) = object else {
-        println!("\n{}", ui.attack_what);
-        return;
+    let Some(obj_name) = object else {
+        println!("\n{}", ui.light_what);
+        return Outcome::failed();
     };
 
-    let Some(target_id) = find_creature_by_name(world, &state.current_room, i18n, target_input)
-    else {
-        println!("\n{}", ui.dont_see);
-        return;
+    let id = match find_object_in_reach(state, world, i18n, obj_name) {
+        Ok(id) => id,
+        Err(miss) => return report_miss(world, i18n, obj_name, miss, &ui.dont_see),
     };
 
-    let target_name = creature_display_name(world, i18n, &target_id);
-
-    let Some((weapon_name, damage)) = best_weapon(state, world, i18n) else {
+    let Some(obj) = world.get_object(&id) else {
+        println!("\n{}", ui.dont_see);
+        return Outcome::failed();
+    };
+    let Some(kind) = obj.light else {
         println!(
             "\n{}",
-            i18n.format(
-                &ui.attack_with,
-                &[
-                    ("target", &target_name),
-                    ("weapon", fists_name(i18n.language()))
-                ],
-            )
+            if obj.is_lit {
+                &ui.already_lit
+            } else {
+                &ui.cant_light
+            }
         );
-        return;
+        return Outcome::failed();
     };
-
-    println!(
-        "\n{}",
-        i18n.format(
-            &ui.attack_with,
-            &[("target", &target_name), ("weapon", &weapon_name)],
-        )
-    );
-
-    let was_alive = world
-        .get_creature(&target_id)
-        .map(|c| c.is_alive())
-        .unwrap_or(false);
-
-    if !was_alive {
-        if let Some(creature) = world.get_creature(&target_id) {
-            println!("\n{}", creature_display_line(creature, i18n));
-        }
-        return;
+    if obj.is_lit {
+        println!("\n{}", ui.already_lit);
+        return Outcome::failed();
     }
-
-    let mut killed = false;
-    if let Some(creature) = world.get_creature_mut(&target_id) {
-        creature.take_damage(damage);
-        killed = creature.state == CreatureState::Dead;
+    if obj.fuel == Some(0) {
+        let spent = match kind {
+            LightKind::Match => &ui.no_matches,
+            _ => light::texts(ui, kind)[3],
+        };
+        println!("\n{}", spent);
+        return Outcome::failed();
     }
 
-    if killed {
-        state.add_score(10);
-        drop_items_from_creature(world, &target_id, &state.current_room);
-        println!("\n{}", i18n.format(&ui.killed, &[("target", &target_name)]));
-        if let Some(creature) = world.get_creature(&target_id) {
-            println!("{}", creature_display_line(creature, i18n));
+    if kind == LightKind::Candle {
+        let source = match flame {
+            Some(flame_name) => match find_object_in_reach(state, world, i18n, flame_name) {
+                Ok(flame_id) => Some(flame_id).filter(|flame_id| is_flame(world, flame_id)),
+                Err(miss) => return report_miss(world, i18n, flame_name, miss, &ui.dont_see),
+            },
+            None => state
+                .inventory
+                .iter()
+                .chain(visible_objects(world, &state.current_room).iter())
+                .find(|flame_id| is_flame(world, flame_id))
+                .cloned(),
+        };
+        if source.is_none() {
+            println!("\n{}", ui.need_flame);
+            return Outcome::failed();
         }
-        return;
     }
 
-    if let Some(attacks_text) = i18n.creature(&target_id).and_then(|c| c.attacks.as_deref()) {
-        println!("\n{}", attacks_text);
+    let was_dark = world.get_room(&state.current_room).is_dark && !light::has_light(state, world);
+    if let Some(obj) = world.get_object_mut(&id) {
+        obj.is_lit = true;
+        if kind == LightKind::Match {
+            obj.fuel = obj.fuel.map(|matches| matches - 1);
+        }
+    }
+    if kind == LightKind::Match {
+        state
+            .timers
+            .schedule_fuse(light::MATCH_FUSE, light::MATCH_BURN);
     } else {
-        println!("\n{}", i18n.format(&ui.dodged, &[("target", &target_name)]));
+        light::start_daemon(state, world);
+    }
+
+    logging::info(format!(
+        "light.on object={} room={}",
+        id, state.current_room
+    ));
+    println!("\n{}", light::texts(ui, kind)[0]);
+    if was_dark && light::has_light(state, world) {
+        cmd_look(state, world, i18n);
     }
+    Outcome::about(vec![id])
 }
 
-fn cmd_put(state: &mut GameState, world: &mut World, object: Option<&str>, i18n: &I18n) {
+fn cmd_extinguish(
+    state: &mut GameState,
+    world: &mut World,
+    object: Option<&str>,
+    i18n: &I18n,
+) -> Outcome {
     let ui = i18n.ui();
 
-    let Some(spec) = object else {
-        println!("\n{}", put_prompt(i18n.language()));
-        return;
-    };
-
-    let Some((item_name, container_name)) = parse_put_spec(spec, i18n.language()) else {
-        println!("\n{}", put_prompt(i18n.language()));
-        return;
+    let Some(obj_name) = object else {
+        println!("\n{}", ui.extinguish_what);
+        return Outcome::failed();
     };
 
-    let Some(item_id) = find_inventory_object_by_name(state, world, i18n, &item_name) else {
-        println!("\n{}", ui.not_holding);
-        return;
+    let id = match find_object_in_reach(state, world, i18n, obj_name) {
+        Ok(id) => id,
+        Err(miss) => return report_miss(world, i18n, obj_name, miss, &ui.dont_see),
     };
 
-    let container_id = find_object_by_name(world, &state.current_room, i18n, &container_name)
-        .or_else(|| find_inventory_object_by_name(state, world, i18n, &container_name));
-
-    let Some(container_id) = container_id else {
+    let Some(obj) = world.get_object_mut(&id) else {
         println!("\n{}", ui.dont_see);
-        return;
+        return Outcome::failed();
     };
+    let Some(kind) = obj.light else {
+        println!("\n{}", ui.cant_extinguish);
+        return Outcome::failed();
+    };
+    if !obj.is_lit {
+        println!("\n{}", ui.not_lit);
+        return Outcome::failed();
+    }
+    obj.is_lit = false;
+    if kind == LightKind::Match {
+        state.timers.cancel(light::MATCH_FUSE);
+    }
 
-    if item_id == container_id {
-        println!("\n{}", ui.cant_put_in);
-        return;
+    logging::info(format!(
+        "light.off object={} room={}",
+        id, state.current_room
+    ));
+    println!("\n{}", light::texts(ui, kind)[1]);
+    if world.get_room(&state.current_room).is_dark && !light::has_light(state, world) {
+        println!("{}", ui.darkness);
     }
+    Outcome::about(vec![id])
+}
 
-    let can_put = world
-        .get_object(&container_id)
-        .map(|obj| obj.is_openable && obj.is_open)
+/// A naked flame that can light candles: a burning match or candle.
+fn is_flame(world: &World, object_id: &str) -> bool {
+    world.get_object(object_id).is_some_and(|obj| {
+        obj.is_lit && matches!(obj.light, Some(LightKind::Match | LightKind::Candle))
+    })
+}
+
+/// Eats (or, with `drink`, drinks) something in reach. It leaves play.
+fn cmd_consume(
+    state: &mut GameState,
+    world: &mut World,
+    object: Option<&str>,
+    drink: bool,
+    i18n: &I18n,
+) -> Outcome {
+    let ui = i18n.ui();
+    let refusal = if drink { &ui.cant_drink } else { &ui.cant_eat };
+
+    let Some(obj_name) = object else {
+        println!("\n{}", refusal);
+        return Outcome::failed();
+    };
+
+    let id = match find_object_in_reach(state, world, i18n, obj_name) {
+        Ok(id) => id,
+        Err(miss) => return report_miss(world, i18n, obj_name, miss, &ui.dont_see),
+    };
+
+    let fits = world
+        .get_object(&id)
+        .is_some_and(|obj| if drink { obj.drinkable } else { obj.edible });
+    if !fits {
+        println!("\n{}", refusal);
+        return Outcome::failed();
+    }
+
+    state.remove_from_inventory(&id);
+    world.consume_object(&id);
+    logging::info(format!(
+        "consume.{} object={} room={}",
+        if drink { "drink" } else { "eat" },
+        id,
+        state.current_room
+    ));
+    println!("\n{}", if drink { &ui.drank } else { &ui.ate });
+    Outcome::about(vec![id])
+}
+
+/// Climbs a tree or a tied rope by moving the way it leads. Without an
+/// object, climbs the one climbable thing in the room.
+fn cmd_climb(
+    state: &mut GameState,
+    world: &mut World,
+    object: Option<&str>,
+    i18n: &I18n,
+) -> Outcome {
+    let ui = i18n.ui();
+
+    let id = match object {
+        Some(obj_name) => match find_object_in_reach(state, world, i18n, obj_name) {
+            Ok(id) => id,
+            Err(miss) => return report_miss(world, i18n, obj_name, miss, &ui.dont_see),
+        },
+        None => {
+            let mut climbable = world
+                .objects_in_room(&state.current_room)
+                .into_iter()
+                .filter(|obj| obj.climb.is_some());
+            match (climbable.next(), climbable.next()) {
+                (Some(obj), None) => obj.id.clone(),
+                _ => {
+                    println!("\n{}", ui.cant_climb);
+                    return Outcome::failed();
+                }
+            }
+        }
+    };
+
+    let direction = world.get_object(&id).and_then(|obj| {
+        let usable = !obj.tieable || obj.tied_to.is_some();
+        obj.climb.clone().filter(|_| usable)
+    });
+    let verb = match direction.as_deref() {
+        Some("up") => Verb::Up,
+        Some("down") => Verb::Down,
+        _ => {
+            println!("\n{}", ui.cant_climb);
+            return Outcome::failed();
+        }
+    };
+
+    logging::info(format!("climb object={} room={}", id, state.current_room));
+    cmd_move(state, world, &verb, i18n)
+}
+
+/// Ties a held object such as the rope to a fixture in the room. The object
+/// stays behind, tied, until it is untied.
+fn cmd_tie(
+    state: &mut GameState,
+    world: &mut World,
+    object: Option<&str>,
+    target: Option<&str>,
+    i18n: &I18n,
+) -> Outcome {
+    let ui = i18n.ui();
+
+    let (Some(obj_name), Some(target_name)) = (object, target) else {
+        println!("\n{}", ui.cant_tie);
+        return Outcome::failed();
+    };
+
+    let id = match find_object_in_reach(state, world, i18n, obj_name) {
+        Ok(id) => id,
+        Err(miss) => return report_miss(world, i18n, obj_name, miss, &ui.dont_see),
+    };
+    let target_id = match find_object_by_name(world, &state.current_room, i18n, target_name) {
+        Ok(target_id) => target_id,
+        Err(miss) => return report_miss(world, i18n, target_name, miss, &ui.dont_see),
+    };
+
+    let tieable = world
+        .get_object(&id)
+        .is_some_and(|obj| obj.tieable && obj.tied_to.is_none());
+    let fixture = world
+        .get_object(&target_id)
+        .is_some_and(|obj| obj.tie_target);
+    if !tieable || !fixture {
+        println!("\n{}", ui.cant_tie);
+        return Outcome::failed();
+    }
+
+    remove_from_container(world, &id);
+    drop_object(state, world, &id);
+    if let Some(obj) = world.get_object_mut(&id) {
+        obj.tied_to = Some(target_id.clone());
+    }
+    logging::info(format!(
+        "tie object={} target={} room={}",
+        id, target_id, state.current_room
+    ));
+    println!("\n{}", ui.tied);
+    Outcome::about(vec![id])
+}
+
+fn cmd_untie(
+    state: &mut GameState,
+    world: &mut World,
+    object: Option<&str>,
+    i18n: &I18n,
+) -> Outcome {
+    let ui = i18n.ui();
+
+    let Some(obj_name) = object else {
+        println!("\n{}", ui.cant_untie);
+        return Outcome::failed();
+    };
+
+    let id = match find_object_by_name(world, &state.current_room, i18n, obj_name) {
+        Ok(id) => id,
+        Err(miss) => return report_miss(world, i18n, obj_name, miss, &ui.dont_see),
+    };
+
+    let Some(target_id) = world.get_object_mut(&id).and_then(|obj| obj.tied_to.take()) else {
+        println!("\n{}", ui.cant_untie);
+        return Outcome::failed();
+    };
+    logging::info(format!("untie object={} target={}", id, target_id));
+    println!("\n{}", ui.untied);
+    Outcome::about(vec![id])
+}
+
+fn cmd_attack(
+    state: &mut GameState,
+    world: &mut World,
+    object: Option<&str>,
+    weapon: Option<&str>,
+    i18n: &I18n,
+) -> Outcome {
+    let ui = i18n.ui();
+
+    let Some(target_input) = object else {
+        println!("\n{}", ui.attack_what);
+        return Outcome::failed();
+    };
+
+    let target_id = match find_creature_by_name(world, &state.current_room, i18n, target_input) {
+        Ok(id) => id,
+        Err(miss) => return report_miss(world, i18n, target_input, miss, &ui.dont_see),
+    };
+
+    let target_name = creature_display_name(world, i18n, &target_id);
+
+    let chosen = match weapon {
+        Some(weapon_input) => {
+            let weapon_id = match find_inventory_object_by_name(state, world, i18n, weapon_input) {
+                Ok(id) => id,
+                Err(miss) => {
+                    return report_miss(world, i18n, weapon_input, miss, &ui.not_holding);
+                }
+            };
+            weapon_stats(&weapon_id)
+                .map(|weapon| (object_display_name(world, i18n, &weapon_id), weapon))
+                .ok_or_else(|| object_display_name(world, i18n, &weapon_id))
+        }
+        None => best_weapon(state, world, i18n).ok_or_else(|| i18n.ui().fists.clone()),
+    };
+
+    let (weapon_name, weapon) = match chosen {
+        Ok(found) => found,
+        Err(harmless) => {
+            println!(
+                "\n{}",
+                i18n.format(
+                    &ui.attack_with,
+                    &[("target", &target_name), ("weapon", &harmless)],
+                )
+            );
+            counterattack(state, world, &target_id, false, i18n);
+            return Outcome::about(vec![target_id]);
+        }
+    };
+
+    println!(
+        "\n{}",
+        i18n.format(
+            &ui.attack_with,
+            &[("target", &target_name), ("weapon", &weapon_name)],
+        )
+    );
+
+    let was_alive = world
+        .get_creature(&target_id)
+        .map(|c| c.is_alive())
         .unwrap_or(false);
 
-    if !can_put {
-        println!("\n{}", ui.cant_put_in);
-        return;
+    if !was_alive {
+        if let Some(creature) = world.get_creature(&target_id) {
+            println!("\n{}", creature_display_line(creature, i18n));
+        }
+        return Outcome::about(vec![target_id]);
     }
 
-    remove_from_container(world, &item_id);
-    world.move_object(&item_id, &container_id);
-    state.remove_from_inventory(&item_id);
+    let hit = state.rng.chance(weapon.hit_chance);
+    logging::info(format!(
+        "combat.attack target={} damage={} chance={} hit={}",
+        target_id, weapon.damage, weapon.hit_chance, hit
+    ));
+    if !hit {
+        println!("\n{}", i18n.format(&ui.dodged, &[("target", &target_name)]));
+        counterattack(state, world, &target_id, true, i18n);
+        return Outcome::about(vec![target_id]);
+    }
 
-    if let Some(container) = world.get_object_mut(&container_id) {
-        if !container.contents.contains(&item_id) {
-            container.contents.push(item_id.clone());
+    let mut killed = false;
+    if let Some(creature) = world.get_creature_mut(&target_id) {
+        creature.take_damage(weapon.damage);
+        killed = creature.state == CreatureState::Dead;
+    }
+
+    if killed {
+        drop_items_from_creature(world, &target_id, &state.current_room);
+        println!("\n{}", i18n.format(&ui.killed, &[("target", &target_name)]));
+        if let Some(creature) = world.get_creature(&target_id) {
+            println!("{}", creature_display_line(creature, i18n));
         }
+        return Outcome::about(vec![target_id]);
+    }
+
+    println!(
+        "\n{}",
+        i18n.format(&ui.you_hit, &[("target", &target_name)])
+    );
+    counterattack(state, world, &target_id, true, i18n);
+    Outcome::about(vec![target_id])
+}
+
+/// A hostile creature that survived an attack strikes back. It lands the
+/// blow less often when the player fights armed.
+fn counterattack(
+    state: &mut GameState,
+    world: &mut World,
+    creature_id: &str,
+    armed: bool,
+    i18n: &I18n,
+) {
+    let Some(creature) = world.get_creature(creature_id) else {
+        return;
+    };
+    if !creature.hostile || !creature.is_alive() {
+        return;
+    }
+    let damage = creature.damage;
+
+    if let Some(attacks_text) = i18n
+        .creature(creature_id)
+        .and_then(|c| c.attacks.as_deref())
+    {
+        println!("\n{}", attacks_text);
+    }
+
+    let chance = if armed { 50 } else { 75 };
+    let hit = state.rng.chance(chance);
+    logging::info(format!(
+        "combat.counter creature={} damage={} chance={} hit={} health={}",
+        creature_id, damage, chance, hit, state.health
+    ));
+    if !hit {
+        println!("{}", i18n.ui().you_parry);
+        return;
+    }
+
+    state.health -= damage;
+    if state.health > 0 {
+        println!("{}", i18n.ui().you_are_hit);
+    } else {
+        player_dies(state, world, i18n);
+    }
+}
+
+/// Deaths the player is brought back from before the game is over.
+const RESURRECTIONS: u32 = 1;
+
+/// Points a resurrection costs.
+const DEATH_PENALTY: u32 = 10;
+
+/// Kills the player. The first death is forgiven: the possessions stay where
+/// the player fell and they wake up elsewhere, poorer in points. After that
+/// `player_dead` is set and `Game` offers restart, restore or quit.
+pub(crate) fn player_dies(state: &mut GameState, world: &mut World, i18n: &I18n) {
+    let ui = i18n.ui();
+    state.deaths += 1;
+    println!("\n{}", ui.you_died);
+
+    let room = world.resurrection_room.clone();
+    let Some(room) = room.filter(|_| state.deaths <= RESURRECTIONS) else {
+        state.player_dead = true;
+        logging::info(format!(
+            "player.dead room={} deaths={} score={} moves={}",
+            state.current_room, state.deaths, state.score, state.moves
+        ));
+        return;
+    };
+
+    for item in std::mem::take(&mut state.inventory) {
+        world.move_object(&item, &state.current_room);
+    }
+    state.score = state.score.saturating_sub(DEATH_PENALTY);
+    state.health = MAX_HEALTH;
+    logging::info(format!(
+        "player.resurrected from={} to={} deaths={} score={}",
+        state.current_room, room, state.deaths, state.score
+    ));
+    state.move_to(&room);
+
+    println!("\n{}", ui.resurrected);
+    cmd_look(state, world, i18n);
+}
+
+fn cmd_put(
+    state: &mut GameState,
+    world: &mut World,
+    item: Option<&str>,
+    container: Option<&str>,
+    i18n: &I18n,
+) -> Outcome {
+    let ui = i18n.ui();
+
+    let (Some(item_name), Some(container_name)) = (item, container) else {
+        println!("\n{}", i18n.ui().put_what);
+        return Outcome::failed();
+    };
+
+    let item_id = match find_inventory_object_by_name(state, world, i18n, item_name) {
+        Ok(id) => id,
+        Err(miss) => return report_miss(world, i18n, item_name, miss, &ui.not_holding),
+    };
+
+    let container_id = match find_object_in_reach(state, world, i18n, container_name) {
+        Ok(id) => id,
+        Err(miss) => return report_miss(world, i18n, container_name, miss, &ui.dont_see),
+    };
+
+    if item_id == container_id || !accepts_items(world, &container_id) {
+        println!("\n{}", ui.cant_put_in);
+        return Outcome::failed();
     }
 
+    put_object(state, world, &item_id, &container_id);
+
     let item_disp = object_display_name(world, i18n, &item_id);
     let container_disp = object_display_name(world, i18n, &container_id);
     println!(
@@ -502,30 +1313,331 @@ fn cmd_put(state: &mut GameState, world: &mut World, object: Option<&str>, i18n:
             &[("obj", &item_disp), ("container", &container_disp)],
         )
     );
+    Outcome::about(vec![item_id])
 }
 
-fn cmd_restore(state: &mut GameState, world: &World, i18n: &I18n) {
+/// `put all in case` / `metti spada e lampada nella cassa`.
+fn cmd_put_many(
+    state: &mut GameState,
+    world: &mut World,
+    selection: &Selection,
+    container: Option<&str>,
+    i18n: &I18n,
+) -> Outcome {
     let ui = i18n.ui();
 
-    match GameState::load(1) {
-        Ok(loaded) => {
-            *state = loaded;
-            logging::info("restore.ok slot=1");
+    let Some(container_name) = container else {
+        println!("\n{}", i18n.ui().put_what);
+        return Outcome::failed();
+    };
+
+    let container_id = match find_object_in_reach(state, world, i18n, container_name) {
+        Ok(id) => id,
+        Err(miss) => return report_miss(world, i18n, container_name, miss, &ui.dont_see),
+    };
+
+    if !accepts_items(world, &container_id) {
+        println!("\n{}", ui.cant_put_in);
+        return Outcome::failed();
+    }
+
+    let pool: Vec<String> = state
+        .inventory
+        .iter()
+        .filter(|id| **id != container_id)
+        .cloned()
+        .collect();
+
+    let targets = resolve_selection(world, i18n, selection, &pool, |name| {
+        find_inventory_object_by_name(state, world, i18n, name)
+    });
+
+    if targets.is_empty() {
+        println!("\n{}", ui.empty_handed);
+        return Outcome::failed();
+    }
+
+    println!();
+    let mut stored = Vec::new();
+    for target in targets {
+        match target {
+            Ok(id) => {
+                let name = object_display_name(world, i18n, &id);
+                if id == container_id {
+                    println!("{}: {}", name, ui.cant_put_in);
+                } else if !state.has_item(&id) {
+                    println!("{}: {}", name, ui.not_holding);
+                } else {
+                    put_object(state, world, &id, &container_id);
+                    stored.push(id.clone());
+                    println!("{}: {}", name, ui.done);
+                }
+            }
+            Err((name, miss)) => println!(
+                "{}: {}",
+                name,
+                miss_text(world, i18n, &miss, &ui.not_holding)
+            ),
+        }
+    }
+
+    logging::info(format!(
+        "put.many container={} stored={}",
+        container_id,
+        stored.join(",")
+    ));
+    if stored.is_empty() {
+        Outcome::failed()
+    } else {
+        Outcome::about(stored)
+    }
+}
+
+fn accepts_items(world: &World, container_id: &str) -> bool {
+    world
+        .get_object(container_id)
+        .map(|obj| obj.is_openable && obj.is_open)
+        .unwrap_or(false)
+}
+
+fn put_object(state: &mut GameState, world: &mut World, item_id: &str, container_id: &str) {
+    remove_from_container(world, item_id);
+    world.move_object(item_id, container_id);
+    state.remove_from_inventory(item_id);
+
+    if let Some(container) = world.get_object_mut(container_id)
+        && !container.contents.iter().any(|id| id == item_id)
+    {
+        container.contents.push(item_id.to_string());
+    }
+    score::deposit(state, world, item_id, container_id);
+}
+
+fn cmd_give(
+    state: &mut GameState,
+    world: &mut World,
+    item: Option<&str>,
+    recipient: Option<&str>,
+    i18n: &I18n,
+) -> Outcome {
+    let ui = i18n.ui();
+
+    let (Some(item_name), Some(recipient_name)) = (item, recipient) else {
+        println!("\n{}", ui.give_what);
+        return Outcome::failed();
+    };
+
+    let item_id = match find_inventory_object_by_name(state, world, i18n, item_name) {
+        Ok(id) => id,
+        Err(miss) => return report_miss(world, i18n, item_name, miss, &ui.not_holding),
+    };
+
+    let creature_id = match find_creature_by_name(world, &state.current_room, i18n, recipient_name)
+    {
+        Ok(id) => id,
+        Err(miss) => return report_miss(world, i18n, recipient_name, miss, &ui.dont_see),
+    };
+
+    if let Some(creature) = world.get_creature(&creature_id)
+        && !creature.is_alive()
+    {
+        println!("\n{}", creature_display_line(creature, i18n));
+        return Outcome::failed();
+    }
+
+    // Creatures keep gifts like loot, so they drop them again on death.
+    world.move_object(&item_id, &creature_id);
+    state.remove_from_inventory(&item_id);
+    logging::info(format!("give.ok item={} to={}", item_id, creature_id));
+
+    let item_disp = object_display_name(world, i18n, &item_id);
+    let target_disp = creature_display_name(world, i18n, &creature_id);
+    println!(
+        "\n{}",
+        i18n.format(&ui.given, &[("obj", &item_disp), ("target", &target_disp)])
+    );
+    Outcome::about(vec![item_id])
+}
+
+fn cmd_save(state: &GameState, world: &World, object: Option<&str>, i18n: &I18n) -> Outcome {
+    let ui = i18n.ui();
+
+    let Some(slot) = save::slot_name(object) else {
+        println!("\n{}", ui.invalid_save_name);
+        return Outcome::failed();
+    };
+
+    match SaveFile::capture(state, world).write(&slot) {
+        Ok(()) => {
+            logging::info(format!("save.ok slot={}", slot));
+            println!("\n{}", ui.game_saved);
+            Outcome::done()
+        }
+        Err(e) => {
+            logging::error(format!("save.failed slot={} error={}", slot, e));
+            println!("\n{}: {}", ui.save_failed, e);
+            Outcome::failed()
+        }
+    }
+}
+
+fn cmd_restore(
+    state: &mut GameState,
+    world: &mut World,
+    object: Option<&str>,
+    i18n: &I18n,
+) -> Outcome {
+    let ui = i18n.ui();
+
+    let Some(slot) = save::slot_name(object) else {
+        println!("\n{}", ui.invalid_save_name);
+        return Outcome::failed();
+    };
+
+    match SaveFile::read(state.game, &slot) {
+        Ok(save) if !save.fits(state.game, world) => {
+            logging::warn(format!(
+                "restore.mismatch slot={} saved_game={:?} running_game={:?} room={}",
+                slot, save.state.game, state.game, save.state.current_room
+            ));
+            match save.state.game {
+                Some(game) => println!(
+                    "\n{}",
+                    i18n.format(&ui.save_wrong_game, &[("game", game.short_title())])
+                ),
+                None => println!("\n{}", ui.save_incompatible),
+            }
+            Outcome::failed()
+        }
+        Ok(mut save) => {
+            let language = state.language.clone();
+            if save.state.language != language {
+                logging::info(format!(
+                    "restore.language saved={} running={}",
+                    save.state.language, language
+                ));
+                save.state.language = language;
+            }
+            if save.state.game.is_none() {
+                save.state.game = state.game;
+            }
+            if save.version == 1 {
+                logging::warn(format!("restore.legacy slot={} world=unchanged", slot));
+            }
+            let version = save.version;
+            save.apply(state, world);
+            logging::info(format!("restore.ok slot={} version={}", slot, version));
             println!("\n{}", ui.game_restored);
             cmd_look(state, world, i18n);
+            Outcome::done()
         }
-        Err(e) if e == "No saved game found" => {
-            logging::warn("restore.missing slot=1");
+        Err(SaveError::NotFound) => {
+            logging::warn(format!("restore.missing slot={}", slot));
             println!("\n{}", ui.no_saved_game);
+            Outcome::failed()
         }
         Err(e) => {
-            logging::error(format!("restore.failed slot=1 error={}", e));
+            logging::error(format!("restore.failed slot={} error={}", slot, e));
             println!("\n{}: {}", ui.restore_failed, e);
+            Outcome::failed()
+        }
+    }
+}
+
+fn cmd_saves(state: &GameState, world: &World, i18n: &I18n) -> Outcome {
+    let ui = i18n.ui();
+    let saves = SaveFile::list(state.game);
+
+    if saves.is_empty() {
+        println!("\n{}", ui.no_saves);
+        return Outcome::done();
+    }
+
+    println!("\n{}", ui.save_list);
+    for summary in saves {
+        let state = &summary.save.state;
+        let game = state.game.map(|g| g.short_title()).unwrap_or("?");
+        let room = i18n
+            .room(&state.current_room)
+            .map(|r| r.name.clone())
+            .or_else(|| world.rooms.get(&state.current_room).map(|r| r.name.clone()))
+            .unwrap_or_else(|| state.current_room.clone());
+        let score = state.score.to_string();
+        let moves = state.moves.to_string();
+        let time = save::format_timestamp(summary.save.saved_at);
+
+        println!(
+            "  {}",
+            i18n.format(
+                &ui.save_entry,
+                &[
+                    ("name", &summary.slot),
+                    ("game", game),
+                    ("room", &room),
+                    ("score", &score),
+                    ("moves", &moves),
+                    ("time", &time),
+                ],
+            )
+        );
+    }
+    Outcome::done()
+}
+
+fn cmd_delete(state: &GameState, object: Option<&str>, i18n: &I18n) -> Outcome {
+    let ui = i18n.ui();
+
+    let name = object
+        .map(|spec| strip_save_noun(spec, i18n.words()))
+        .filter(|n| !n.is_empty());
+    let Some(name) = name else {
+        println!("\n{}", ui.delete_what);
+        return Outcome::failed();
+    };
+
+    let Some(slot) = save::slot_name(Some(name)) else {
+        println!("\n{}", ui.invalid_save_name);
+        return Outcome::failed();
+    };
+
+    match SaveFile::delete(state.game, &slot) {
+        Ok(()) => {
+            logging::info(format!("save.deleted slot={}", slot));
+            println!("\n{}", i18n.format(&ui.save_deleted, &[("name", &slot)]));
+            Outcome::done()
+        }
+        Err(SaveError::NotFound) => {
+            logging::warn(format!("save.delete.missing slot={}", slot));
+            println!("\n{}", ui.no_saved_game);
+            Outcome::failed()
+        }
+        Err(e) => {
+            logging::error(format!("save.delete.failed slot={} error={}", slot, e));
+            println!("\n{}: {}", ui.save_failed, e);
+            Outcome::failed()
         }
     }
 }
 
-fn cmd_enter(state: &mut GameState, world: &mut World, object: Option<&str>, i18n: &I18n) {
+/// `delete save foo` / `cancella salvataggio foo` / `borrar partida foo`,
+/// with the nouns from the pack's `save_nouns`.
+fn strip_save_noun<'a>(spec: &'a str, words: &Words) -> &'a str {
+    let spec = spec.trim();
+    let is_noun = |word: &str| words.save_nouns.iter().any(|noun| noun == word);
+
+    match spec.split_once(' ') {
+        Some((head, rest)) if is_noun(head) => rest.trim(),
+        _ if is_noun(spec) => "",
+        _ => spec,
+    }
+}
+
+fn cmd_enter(
+    state: &mut GameState,
+    world: &mut World,
+    object: Option<&str>,
+    i18n: &I18n,
+) -> Outcome {
     let ui = i18n.ui();
     let room_id = state.current_room.clone();
 
@@ -537,7 +1649,7 @@ fn cmd_enter(state: &mut GameState, world: &mut World, object: Option<&str>, i18
 
     if enterable_objects.is_empty() {
         println!("\n{}", ui.cant_go);
-        return;
+        return Outcome::failed();
     }
 
     let target = if let Some(name) = object {
@@ -551,73 +1663,44 @@ fn cmd_enter(state: &mut GameState, world: &mut World, object: Option<&str>, i18
 
     let Some(obj) = target else {
         println!("\n{}", ui.dont_see);
-        return;
+        return Outcome::failed();
     };
 
     if obj.is_openable && !obj.is_open {
         println!("\n{}", ui.locked);
-        return;
+        return Outcome::failed();
     }
 
     if let Some(destination) = obj.enter_destination.clone() {
         state.move_to(&destination);
+        score::enter_room(state, world, &destination);
         logging::info(format!("enter.ok from={} to={}", room_id, destination));
         cmd_look(state, world, i18n);
     }
+    Outcome::about(vec![obj.id.clone()])
 }
 
-fn use_prompt(lang: Language) -> &'static str {
-    match lang {
-        Language::English => "Use what?",
-        Language::Italian => "Usare cosa?",
-        Language::Spanish => "Usar que?",
-    }
-}
+fn find_creature_by_name(
+    world: &World,
+    room_id: &str,
+    i18n: &I18n,
+    name: &str,
+) -> Result<String, Miss> {
+    let candidates = world
+        .creatures_in_room(room_id)
+        .into_iter()
+        .map(|creature| creature.id.clone());
 
-fn put_prompt(lang: Language) -> &'static str {
-    match lang {
-        Language::English => "Put what in what?",
-        Language::Italian => "Mettere cosa in cosa?",
-        Language::Spanish => "Poner que en que?",
-    }
+    pick_by_name(candidates, name, |id| creature_aliases(world, i18n, id))
 }
 
-fn parse_put_spec(spec: &str, lang: Language) -> Option<(String, String)> {
-    let separators = match lang {
-        Language::English => vec![" into ", " in ", " inside "],
-        Language::Italian => vec![
-            " dentro ", " nella ", " nello ", " negli ", " nelle ", " nel ", " nei ", " in ",
-        ],
-        Language::Spanish => vec![" dentro ", " en "],
-    };
-
-    let normalized_spec = normalize_text(spec);
+fn creature_aliases(world: &World, i18n: &I18n, creature_id: &str) -> Vec<String> {
+    let mut aliases: Vec<String> = vec![creature_id.to_string()];
 
-    for sep in separators {
-        let sep_norm = normalize_text(sep);
-        if let Some((left, right)) = normalized_spec.split_once(&sep_norm) {
-            let item = left.trim().to_string();
-            let container = right.trim().to_string();
-            if !item.is_empty() && !container.is_empty() {
-                return Some((item, container));
-            }
-        }
+    if let Some(creature) = world.get_creature(creature_id) {
+        aliases.push(creature.name.clone());
     }
 
-    None
-}
-
-fn find_creature_by_name(world: &World, room_id: &str, i18n: &I18n, name: &str) -> Option<String> {
-    world
-        .creatures_in_room(room_id)
-        .into_iter()
-        .find(|creature| creature_matches_input(i18n, creature.id.as_str(), &creature.name, name))
-        .map(|creature| creature.id.clone())
-}
-
-fn creature_matches_input(i18n: &I18n, creature_id: &str, world_name: &str, input: &str) -> bool {
-    let mut aliases: Vec<String> = vec![creature_id.to_string(), world_name.to_string()];
-
     if let Some(trans) = i18n.creature(creature_id) {
         aliases.push(trans.name.clone());
     }
@@ -626,7 +1709,7 @@ fn creature_matches_input(i18n: &I18n, creature_id: &str, world_name: &str, inpu
         aliases.push(obj_trans.name.clone());
     }
 
-    aliases.iter().any(|alias| is_name_match(alias, input))
+    aliases
 }
 
 fn creature_display_name(world: &World, i18n: &I18n, creature_id: &str) -> String {
@@ -641,7 +1724,10 @@ fn creature_display_name(world: &World, i18n: &I18n, creature_id: &str) -> Strin
     creature_id.to_string()
 }
 
-fn creature_display_line(creature: &crate::game::world::Creature, i18n: &I18n) -> String {
+pub(crate) fn creature_display_line(
+    creature: &crate::game::world::Creature,
+    i18n: &I18n,
+) -> String {
     if let Some(trans) = i18n.creature(&creature.id) {
         if creature.state == CreatureState::Dead {
             if let Some(dead_desc) = trans.dead_desc.as_ref() {
@@ -668,46 +1754,113 @@ fn drop_items_from_creature(world: &mut World, creature_id: &str, room_id: &str)
     }
 }
 
-fn best_weapon(state: &GameState, world: &World, i18n: &I18n) -> Option<(String, i32)> {
-    let weapon_priority = [("sword", 3), ("axe", 3), ("stiletto", 2), ("knife", 2)];
+#[derive(Debug, Clone, Copy)]
+struct Weapon {
+    damage: i32,
+    /// Percent chance that a blow lands.
+    hit_chance: u32,
+}
+
+const WEAPONS: [(&str, Weapon); 4] = [
+    (
+        "sword",
+        Weapon {
+            damage: 3,
+            hit_chance: 80,
+        },
+    ),
+    (
+        "axe",
+        Weapon {
+            damage: 3,
+            hit_chance: 65,
+        },
+    ),
+    (
+        "stiletto",
+        Weapon {
+            damage: 2,
+            hit_chance: 70,
+        },
+    ),
+    (
+        "knife",
+        Weapon {
+            damage: 2,
+            hit_chance: 60,
+        },
+    ),
+];
 
-    for (weapon_id, damage) in weapon_priority {
+fn weapon_stats(object_id: &str) -> Option<Weapon> {
+    WEAPONS
+        .iter()
+        .find(|(weapon_id, _)| *weapon_id == object_id)
+        .map(|(_, weapon)| *weapon)
+}
+
+fn best_weapon(state: &GameState, world: &World, i18n: &I18n) -> Option<(String, Weapon)> {
+    for (weapon_id, weapon) in WEAPONS {
         if state.inventory.iter().any(|item| item == weapon_id) {
-            return Some((object_display_name(world, i18n, weapon_id), damage));
+            return Some((object_display_name(world, i18n, weapon_id), weapon));
         }
     }
 
     None
 }
 
-fn fists_name(lang: Language) -> &'static str {
-    match lang {
-        Language::English => "fists",
-        Language::Italian => "pugni",
-        Language::Spanish => "punos",
+/// Resolves `name` among the objects in the room, including the contents of
+/// open containers.
+fn find_object_by_name(
+    world: &World,
+    room_id: &str,
+    i18n: &I18n,
+    name: &str,
+) -> Result<String, Miss> {
+    pick_by_name(visible_objects(world, room_id), name, |id| {
+        object_aliases(world, i18n, id)
+    })
+}
+
+/// Resolves `name` among everything the player can touch: the room, the
+/// inventory and whatever sits in the open containers the player carries.
+fn find_object_in_reach(
+    state: &GameState,
+    world: &World,
+    i18n: &I18n,
+    name: &str,
+) -> Result<String, Miss> {
+    let mut candidates = visible_objects(world, &state.current_room);
+    for item_id in &state.inventory {
+        candidates.push(item_id.clone());
+        if let Some(obj) = world.get_object(item_id)
+            && obj.is_openable
+            && obj.is_open
+        {
+            candidates.extend(obj.contents.iter().cloned());
+        }
     }
+
+    pick_by_name(candidates, name, |id| object_aliases(world, i18n, id))
 }
 
-fn find_object_by_name(world: &World, room_id: &str, i18n: &I18n, name: &str) -> Option<String> {
-    let objects = world.objects_in_room(room_id);
+fn visible_objects(world: &World, room_id: &str) -> Vec<String> {
+    let mut visible = Vec::new();
 
-    for obj in objects {
-        if object_matches_input(world, i18n, &obj.id, name) {
-            return Some(obj.id.clone());
-        }
+    for obj in world.objects_in_room(room_id) {
+        visible.push(obj.id.clone());
 
         if obj.is_openable && obj.is_open {
-            for content_id in &obj.contents.clone() {
-                if world.get_object(content_id).is_some()
-                    && object_matches_input(world, i18n, content_id, name)
-                {
-                    return Some(content_id.clone());
-                }
-            }
+            visible.extend(
+                obj.contents
+                    .iter()
+                    .filter(|id| world.get_object(id).is_some())
+                    .cloned(),
+            );
         }
     }
 
-    None
+    visible
 }
 
 fn find_inventory_object_by_name(
@@ -715,39 +1868,184 @@ fn find_inventory_object_by_name(
     world: &World,
     i18n: &I18n,
     name: &str,
-) -> Option<String> {
-    state
-        .inventory
-        .iter()
-        .find(|id| object_matches_input(world, i18n, id, name))
-        .cloned()
+) -> Result<String, Miss> {
+    pick_by_name(state.inventory.iter().cloned(), name, |id| {
+        object_aliases(world, i18n, id)
+    })
 }
 
-fn object_matches_input(world: &World, i18n: &I18n, object_id: &str, input: &str) -> bool {
-    let mut aliases: Vec<String> = vec![object_id.to_string()];
+/// Why a name didn't pick out exactly one thing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Miss {
+    NotFound,
+    Ambiguous(Vec<String>),
+}
 
-    if let Some(obj) = world.get_object(object_id) {
-        aliases.push(obj.name.clone());
-    }
+/// Picks the candidate whose aliases best match `name`: an exact match on an
+/// id or name beats a partial one, and a tie at the top is ambiguous.
+fn pick_by_name(
+    candidates: impl IntoIterator<Item = String>,
+    name: &str,
+    aliases: impl Fn(&str) -> Vec<String>,
+) -> Result<String, Miss> {
+    let mut best_rank = 0;
+    let mut best: Vec<String> = Vec::new();
 
-    if let Some(trans) = i18n.object(object_id) {
-        aliases.push(trans.name.clone());
+    for id in candidates {
+        let rank = aliases(&id)
+            .iter()
+            .map(|alias| match_rank(alias, name))
+            .max()
+            .unwrap_or(0);
+
+        if rank == 0 || rank < best_rank || best.contains(&id) {
+            continue;
+        }
+        if rank > best_rank {
+            best_rank = rank;
+            best.clear();
+        }
+        best.push(id);
     }
 
-    aliases.iter().any(|alias| is_name_match(alias, input))
+    match best.len() {
+        0 => Err(Miss::NotFound),
+        1 => Ok(best.remove(0)),
+        _ => {
+            best.sort();
+            Err(Miss::Ambiguous(best))
+        }
+    }
 }
 
-fn is_name_match(alias: &str, input: &str) -> bool {
+/// 2 for an exact match, 1 for a partial one, 0 for none.
+fn match_rank(alias: &str, input: &str) -> u8 {
     let normalized_alias = normalize_text(alias);
     let normalized_input = normalize_text(input);
 
     if normalized_alias.is_empty() || normalized_input.is_empty() {
-        return false;
+        0
+    } else if normalized_alias == normalized_input {
+        2
+    } else if normalized_alias.contains(&normalized_input)
+        || normalized_input.contains(&normalized_alias)
+    {
+        1
+    } else {
+        0
     }
+}
 
-    normalized_alias == normalized_input
-        || normalized_alias.contains(&normalized_input)
-        || normalized_input.contains(&normalized_alias)
+/// Prints why `name` didn't resolve: `not_found`, or a question listing the
+/// candidates, which `Game` answers with the player's next input.
+fn report_miss(world: &World, i18n: &I18n, name: &str, miss: Miss, not_found: &str) -> Outcome {
+    println!("\n{}", miss_text(world, i18n, &miss, not_found));
+
+    match miss {
+        Miss::NotFound => Outcome::failed(),
+        Miss::Ambiguous(candidates) => {
+            logging::info(format!(
+                "resolve.ambiguous name={} candidates={}",
+                name,
+                candidates.join(",")
+            ));
+            Outcome {
+                failed: true,
+                ambiguity: Some(Ambiguity {
+                    phrase: name.to_string(),
+                    candidates,
+                }),
+                ..Outcome::default()
+            }
+        }
+    }
+}
+
+fn miss_text(world: &World, i18n: &I18n, miss: &Miss, not_found: &str) -> String {
+    match miss {
+        Miss::NotFound => not_found.to_string(),
+        Miss::Ambiguous(candidates) => which_question(world, i18n, candidates),
+    }
+}
+
+/// "Which do you mean, the rusty key or the skeleton key?"
+fn which_question(world: &World, i18n: &I18n, candidates: &[String]) -> String {
+    let ui = i18n.ui();
+    let names: Vec<String> = candidates
+        .iter()
+        .map(|id| {
+            let name = thing_display_name(world, i18n, id);
+            i18n.format(&ui.which_option, &[("name", &name)])
+        })
+        .collect();
+
+    let options = match names.split_last() {
+        Some((last, [])) => last.clone(),
+        Some((last, rest)) => format!("{} {} {}", rest.join(", "), ui.which_or, last),
+        None => String::new(),
+    };
+
+    i18n.format(&ui.which_one, &[("options", &options)])
+}
+
+/// Answers a disambiguation question: the candidate the reply names, if it
+/// names exactly one. Replies like `the rusty one` fall back to matching
+/// single words.
+pub fn choose_candidate(
+    world: &World,
+    i18n: &I18n,
+    candidates: &[String],
+    reply: &str,
+) -> Option<String> {
+    let aliases = |id: &str| {
+        if world.get_creature(id).is_some() {
+            creature_aliases(world, i18n, id)
+        } else {
+            object_aliases(world, i18n, id)
+        }
+    };
+
+    match pick_by_name(candidates.iter().cloned(), reply, aliases) {
+        Ok(id) => Some(id),
+        Err(Miss::Ambiguous(_)) => None,
+        Err(Miss::NotFound) => {
+            let mut named: Vec<String> = reply
+                .split_whitespace()
+                .filter_map(|word| pick_by_name(candidates.iter().cloned(), word, aliases).ok())
+                .collect();
+            named.sort();
+            named.dedup();
+            (named.len() == 1).then(|| named.remove(0))
+        }
+    }
+}
+
+fn thing_display_name(world: &World, i18n: &I18n, id: &str) -> String {
+    if world.get_creature(id).is_some() {
+        creature_display_name(world, i18n, id)
+    } else {
+        object_display_name(world, i18n, id)
+    }
+}
+
+fn object_matches_input(world: &World, i18n: &I18n, object_id: &str, input: &str) -> bool {
+    object_aliases(world, i18n, object_id)
+        .iter()
+        .any(|alias| match_rank(alias, input) > 0)
+}
+
+fn object_aliases(world: &World, i18n: &I18n, object_id: &str) -> Vec<String> {
+    let mut aliases: Vec<String> = vec![object_id.to_string()];
+
+    if let Some(obj) = world.get_object(object_id) {
+        aliases.push(obj.name.clone());
+    }
+
+    if let Some(trans) = i18n.object(object_id) {
+        aliases.push(trans.name.clone());
+    }
+
+    aliases
 }
 
 fn normalize_text(value: &str) -> String {
@@ -770,7 +2068,7 @@ fn normalize_text(value: &str) -> String {
     mapped.split_whitespace().collect::<Vec<_>>().join(" ")
 }
 
-fn object_display_name(world: &World, i18n: &I18n, object_id: &str) -> String {
+pub(crate) fn object_display_name(world: &World, i18n: &I18n, object_id: &str) -> String {
     if let Some(obj_trans) = i18n.object(object_id) {
         return obj_trans.name.clone();
     }
@@ -786,8 +2084,13 @@ fn object_display_line(world: &World, i18n: &I18n, object_id: &str) -> Option<St
     let lang = i18n.language();
 
     if let Some(obj_trans) = i18n.object(object_id) {
-        if should_fallback_from_description(i18n.language(), &obj_trans.description) {
-            if lang != Language::English
+        if world.get_object(object_id).is_some_and(|obj| obj.is_lit)
+            && let Some(on_desc) = &obj_trans.on_desc
+        {
+            return Some(on_desc.clone());
+        }
+        if coverage::reads_as_english(lang, &obj_trans.description) {
+            if *lang != Language::ENGLISH
                 && looks_untranslated_name(world, object_id, &obj_trans.name)
             {
                 return None;
@@ -799,26 +2102,13 @@ fn object_display_line(world: &World, i18n: &I18n, object_id: &str) -> Option<St
         return Some(obj_trans.description.clone());
     }
 
-    if lang != Language::English {
+    if *lang != Language::ENGLISH {
         return None;
     }
 
     Some(object_display_name(world, i18n, object_id))
 }
 
-fn should_fallback_from_description(lang: Language, description: &str) -> bool {
-    if lang == Language::English {
-        return false;
-    }
-
-    let normalized = normalize_text(description);
-    let english_prefixes = ["there is ", "there are ", "you see ", "you can see "];
-
-    english_prefixes
-        .iter()
-        .any(|prefix| normalized.starts_with(prefix))
-}
-
 fn looks_untranslated_name(world: &World, object_id: &str, translated_name: &str) -> bool {
     let Some(obj) = world.get_object(object_id) else {
         return false;
@@ -834,6 +2124,9 @@ fn looks_untranslated_name(world: &World, object_id: &str, translated_name: &str
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::game::GameChoice;
+    use crate::game::rng::Rng;
+    use crate::game::world::{NOWHERE, Object};
     use crate::i18n::Language;
     use std::path::PathBuf;
     use std::sync::{Mutex, OnceLock};
@@ -865,122 +2158,294 @@ mod tests {
             },
         }
 
-        let _ = std::fs::remove_dir_all(temp_home);
-        result
+        let _ = std::fs::remove_dir_all(temp_home);
+        result
+    }
+
+    #[test]
+    fn troll_blocks_passage_until_killed() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+        let mut world = World::load_zork1();
+        let mut state = GameState::new(Language::ENGLISH, "ew_passage");
+        state.rng = Rng::new(3);
+
+        execute(&mut state, &mut world, Command::new(Verb::West), &i18n);
+        assert_eq!(state.current_room, "ew_passage");
+
+        state.current_room = "troll_room".to_string();
+        state.add_to_inventory("sword".to_string());
+        world.move_object("sword", "inventory");
+
+        execute(
+            &mut state,
+            &mut world,
+            Command::new(Verb::Attack).with_direct("troll"),
+            &i18n,
+        );
+
+        let troll = world
+            .get_creature("troll")
+            .expect("troll creature should exist");
+        assert_eq!(troll.state, CreatureState::Dead);
+        assert_eq!(world.object_location("axe"), Some("troll_room"));
+
+        state.current_room = "ew_passage".to_string();
+        execute(&mut state, &mut world, Command::new(Verb::West), &i18n);
+        assert_eq!(state.current_room, "troll_room");
+    }
+
+    #[test]
+    fn troll_guards_both_ways_out_of_its_room() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+        let mut world = World::load_zork1();
+        let mut state = GameState::new(Language::ENGLISH, "cellar");
+
+        execute(&mut state, &mut world, Command::new(Verb::North), &i18n);
+        assert_eq!(state.current_room, "troll_room");
+        for verb in [Verb::East, Verb::West] {
+            execute(&mut state, &mut world, Command::new(verb), &i18n);
+            assert_eq!(state.current_room, "troll_room");
+        }
+
+        world
+            .get_creature_mut("troll")
+            .expect("troll creature should exist")
+            .state = CreatureState::Dead;
+        execute(&mut state, &mut world, Command::new(Verb::West), &i18n);
+        assert_eq!(state.current_room, "maze_1");
+        execute(&mut state, &mut world, Command::new(Verb::East), &i18n);
+        execute(&mut state, &mut world, Command::new(Verb::East), &i18n);
+        assert_eq!(state.current_room, "ew_passage");
+    }
+
+    #[test]
+    fn save_restore_roundtrip_via_commands() {
+        with_temp_home("save-restore", |_| {
+            let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+            let mut world = World::load_zork1();
+            let mut state = GameState::new(Language::ENGLISH, "west_of_house");
+
+            execute(&mut state, &mut world, Command::new(Verb::Save), &i18n);
+
+            assert!(GameState::save_exists(None, "1"));
+
+            state.current_room = "north_of_house".to_string();
+            state.score = 77;
+            state.add_to_inventory("sword".to_string());
+
+            execute(&mut state, &mut world, Command::new(Verb::Restore), &i18n);
+
+            assert_eq!(state.current_room, "west_of_house");
+            assert_eq!(state.score, 0);
+            assert!(state.inventory.is_empty());
+            assert_eq!(state.moves, 1);
+        });
+    }
+
+    #[test]
+    fn named_slots_save_list_and_delete() {
+        with_temp_home("named-slots", |_| {
+            let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+            let mut world = World::load_zork1();
+            let mut state = GameState::new(Language::ENGLISH, "west_of_house");
+
+            execute(
+                &mut state,
+                &mut world,
+                Command::new(Verb::Save).with_direct("Before Troll"),
+                &i18n,
+            );
+            state.current_room = "kitchen".to_string();
+            execute(
+                &mut state,
+                &mut world,
+                Command::new(Verb::Save).with_direct("kitchen"),
+                &i18n,
+            );
+
+            let slots: Vec<String> = SaveFile::list(None).into_iter().map(|s| s.slot).collect();
+            assert_eq!(slots, vec!["before_troll", "kitchen"]);
+            assert!(!GameState::save_exists(None, "1"));
+
+            execute(
+                &mut state,
+                &mut world,
+                Command::new(Verb::Restore).with_direct("before troll"),
+                &i18n,
+            );
+            assert_eq!(state.current_room, "west_of_house");
+
+            execute(
+                &mut state,
+                &mut world,
+                Command::new(Verb::Delete).with_direct("save kitchen"),
+                &i18n,
+            );
+            assert!(!GameState::save_exists(None, "kitchen"));
+            assert!(GameState::save_exists(None, "before_troll"));
+
+            assert_eq!(SaveFile::delete(None, "kitchen"), Err(SaveError::NotFound));
+            let missing = execute(
+                &mut state,
+                &mut world,
+                Command::new(Verb::Restore).with_direct("kitchen"),
+                &i18n,
+            );
+            assert!(missing.failed);
+        });
     }
 
     #[test]
-    fn troll_blocks_passage_until_killed() {
-        let i18n = I18n::load(Language::English).expect("translation should load");
-        let mut world = World::load_zork1();
-        let mut state = GameState::new(Language::English, "ew_passage");
-
-        execute(
-            &mut state,
-            &mut world,
-            Command {
-                verb: Verb::West,
-                object: None,
-            },
-            &i18n,
-        );
-        assert_eq!(state.current_room, "ew_passage");
+    fn unwritable_save_directory_is_an_io_error() {
+        with_temp_home("unwritable-saves", |home| {
+            let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+            let mut world = World::load_zork1();
+            let mut state = GameState::new(Language::ENGLISH, "west_of_house");
+            std::fs::write(home.join(".zork-termux"), "").expect("blocker file");
 
-        state.current_room = "troll_room".to_string();
-        state.add_to_inventory("sword".to_string());
-        world.move_object("sword", "inventory");
+            let result = SaveFile::capture(&state, &world).write("1");
+            assert!(matches!(result, Err(SaveError::Io(_))), "{:?}", result);
 
-        execute(
-            &mut state,
-            &mut world,
-            Command {
-                verb: Verb::Attack,
-                object: Some("troll".to_string()),
-            },
-            &i18n,
-        );
+            let outcome = execute(
+                &mut state,
+                &mut world,
+                Command::new(Verb::Save).with_direct("1"),
+                &i18n,
+            );
+            assert!(outcome.failed);
+        });
+    }
 
-        let troll = world
-            .get_creature("troll")
-            .expect("troll creature should exist");
-        assert_eq!(troll.state, CreatureState::Dead);
-        assert_eq!(world.object_location("axe"), Some("troll_room"));
+    #[test]
+    fn save_nouns_come_from_the_pack() {
+        let italian = I18n::load(Language::ITALIAN).expect("translation should load");
+        let english = I18n::load(Language::ENGLISH).expect("translation should load");
 
-        state.current_room = "ew_passage".to_string();
-        execute(
-            &mut state,
-            &mut world,
-            Command {
-                verb: Verb::West,
-                object: None,
-            },
-            &i18n,
+        assert_eq!(
+            strip_save_noun("salvataggio cucina", italian.words()),
+            "cucina"
         );
-        assert_eq!(state.current_room, "troll_room");
+        assert_eq!(strip_save_noun(" partita ", italian.words()), "");
+        assert_eq!(strip_save_noun("save kitchen", english.words()), "kitchen");
+        assert_eq!(strip_save_noun("partita", english.words()), "partita");
     }
 
     #[test]
-    fn save_restore_roundtrip_via_commands() {
-        with_temp_home("save-restore", |_| {
-            let i18n = I18n::load(Language::English).expect("translation should load");
+    fn saves_are_namespaced_per_game() {
+        with_temp_home("per-game-saves", |home| {
+            let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+            let mut zork3_world = World::load_zork3();
+            let mut zork3_state = GameState::new(Language::ENGLISH, "cp_ante");
+            zork3_state.game = Some(GameChoice::Zork3);
+
+            execute(
+                &mut zork3_state,
+                &mut zork3_world,
+                Command::new(Verb::Save),
+                &i18n,
+            );
+            assert!(home.join(".zork-termux/zork3/save_1.json").exists());
+
             let mut world = World::load_zork1();
-            let mut state = GameState::new(Language::English, "west_of_house");
+            let mut state = GameState::new(Language::ENGLISH, "west_of_house");
+            state.game = Some(GameChoice::Zork1);
+            assert!(SaveFile::list(state.game).is_empty());
+
+            let save = SaveFile::read(Some(GameChoice::Zork3), "1").expect("zork3 save");
+            assert!(!save.fits(state.game, &world));
+            save.write("stray").expect("write stray save");
+            std::fs::create_dir_all(home.join(".zork-termux/zork1")).expect("mkdir");
+            std::fs::rename(
+                home.join(".zork-termux/zork3/save_stray.json"),
+                home.join(".zork-termux/zork1/save_stray.json"),
+            )
+            .expect("move save into the zork1 namespace");
 
             execute(
                 &mut state,
                 &mut world,
-                Command {
-                    verb: Verb::Save,
-                    object: None,
-                },
+                Command::new(Verb::Restore).with_direct("stray"),
                 &i18n,
             );
+            assert_eq!(state.current_room, "west_of_house");
+            assert_eq!(state.game, Some(GameChoice::Zork1));
+        });
+    }
 
-            assert!(GameState::save_exists(1));
-
-            state.current_room = "north_of_house".to_string();
-            state.score = 77;
+    #[test]
+    fn restore_rewinds_world_state() {
+        with_temp_home("restore-world", |_| {
+            let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+            let mut world = World::load_zork1();
+            let mut state = GameState::new(Language::ENGLISH, "troll_room");
+            state.rng = Rng::new(3);
             state.add_to_inventory("sword".to_string());
+            world.move_object("sword", "inventory");
+
+            execute(&mut state, &mut world, Command::new(Verb::Save), &i18n);
 
             execute(
                 &mut state,
                 &mut world,
-                Command {
-                    verb: Verb::Restore,
-                    object: None,
-                },
+                Command::new(Verb::Attack).with_direct("troll"),
                 &i18n,
             );
+            world
+                .get_object_mut("trap_door")
+                .expect("trap_door should exist")
+                .is_open = true;
+            assert_eq!(
+                world.get_creature("troll").map(|c| c.state.clone()),
+                Some(CreatureState::Dead)
+            );
 
-            assert_eq!(state.current_room, "west_of_house");
-            assert_eq!(state.score, 0);
-            assert!(state.inventory.is_empty());
-            assert_eq!(state.moves, 1);
+            execute(&mut state, &mut world, Command::new(Verb::Restore), &i18n);
+
+            let troll = world.get_creature("troll").expect("troll should exist");
+            assert!(troll.is_alive());
+            assert_eq!(troll.hp, troll.max_hp);
+            assert_eq!(world.object_location("axe"), Some("troll"));
+            assert!(!world.get_object("trap_door").expect("trap_door").is_open);
+        });
+    }
+
+    #[test]
+    fn legacy_state_only_save_still_restores() {
+        with_temp_home("restore-legacy", |_| {
+            let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+            let mut world = World::load_zork1();
+            let mut state = GameState::new(Language::ENGLISH, "west_of_house");
+
+            let mut legacy = GameState::new(Language::ENGLISH, "kitchen");
+            legacy.score = 5;
+            let path = GameState::save_path(None, "1");
+            std::fs::create_dir_all(path.parent().expect("save dir")).expect("mkdir");
+            std::fs::write(&path, serde_json::to_string(&legacy).expect("serialize"))
+                .expect("write legacy save");
+
+            execute(&mut state, &mut world, Command::new(Verb::Restore), &i18n);
+
+            assert_eq!(state.current_room, "kitchen");
+            assert_eq!(state.score, 5);
         });
     }
 
     #[test]
     fn put_moves_item_into_open_container() {
-        let i18n = I18n::load(Language::English).expect("translation should load");
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
         let mut world = World::load_zork1();
-        let mut state = GameState::new(Language::English, "west_of_house");
+        let mut state = GameState::new(Language::ENGLISH, "west_of_house");
 
         execute(
             &mut state,
             &mut world,
-            Command {
-                verb: Verb::Open,
-                object: Some("mailbox".to_string()),
-            },
+            Command::new(Verb::Open).with_direct("mailbox"),
             &i18n,
         );
         execute(
             &mut state,
             &mut world,
-            Command {
-                verb: Verb::Take,
-                object: Some("leaflet".to_string()),
-            },
+            Command::new(Verb::Take).with_direct("leaflet"),
             &i18n,
         );
         assert!(state.inventory.contains(&"advertisement".to_string()));
@@ -988,10 +2453,9 @@ mod tests {
         execute(
             &mut state,
             &mut world,
-            Command {
-                verb: Verb::Put,
-                object: Some("leaflet in mailbox".to_string()),
-            },
+            Command::new(Verb::Put)
+                .with_direct("leaflet")
+                .with_indirect(Preposition::In, "mailbox"),
             &i18n,
         );
 
@@ -1003,11 +2467,62 @@ mod tests {
         assert!(mailbox.contents.iter().any(|id| id == "advertisement"));
     }
 
+    #[test]
+    fn attack_uses_named_weapon_and_gifts_drop_on_death() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+        let mut world = World::load_zork1();
+        let mut state = GameState::new(Language::ENGLISH, "round_room");
+        state.rng = Rng::new(3);
+        for item in ["knife", "garlic"] {
+            state.add_to_inventory(item.to_string());
+            world.move_object(item, "inventory");
+        }
+
+        execute(
+            &mut state,
+            &mut world,
+            Command::new(Verb::Give)
+                .with_direct("garlic")
+                .with_indirect(Preposition::To, "thief"),
+            &i18n,
+        );
+        assert!(!state.has_item("garlic"));
+        assert_eq!(world.object_location("garlic"), Some("thief"));
+
+        execute(
+            &mut state,
+            &mut world,
+            Command::new(Verb::Attack)
+                .with_direct("thief")
+                .with_indirect(Preposition::With, "sword"),
+            &i18n,
+        );
+        assert!(world.get_creature("thief").expect("thief").is_alive());
+
+        // Blows can miss, so keep swinging; the knife kills in one hit.
+        for _ in 0..10 {
+            if !world.get_creature("thief").expect("thief").is_alive() {
+                break;
+            }
+            execute(
+                &mut state,
+                &mut world,
+                Command::new(Verb::Attack)
+                    .with_direct("thief")
+                    .with_indirect(Preposition::With, "knife"),
+                &i18n,
+            );
+        }
+        assert!(!world.get_creature("thief").expect("thief").is_alive());
+        assert_eq!(world.object_location("garlic"), Some("round_room"));
+    }
+
     #[test]
     fn thief_can_be_killed_with_weapon() {
-        let i18n = I18n::load(Language::English).expect("translation should load");
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
         let mut world = World::load_zork1();
-        let mut state = GameState::new(Language::English, "round_room");
+        let mut state = GameState::new(Language::ENGLISH, "round_room");
+        state.rng = Rng::new(3);
         state.add_to_inventory("sword".to_string());
         world.move_object("sword", "inventory");
 
@@ -1017,10 +2532,7 @@ mod tests {
         execute(
             &mut state,
             &mut world,
-            Command {
-                verb: Verb::Attack,
-                object: Some("thief".to_string()),
-            },
+            Command::new(Verb::Attack).with_direct("thief"),
             &i18n,
         );
 
@@ -1028,11 +2540,177 @@ mod tests {
         assert_eq!(thief.state, CreatureState::Dead);
     }
 
+    #[test]
+    fn troll_fights_back_until_the_player_dies_twice() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+        let mut world = World::load_zork1();
+        let mut state = GameState::new(Language::ENGLISH, "troll_room");
+        state.rng = Rng::new(3);
+        state.score = 25;
+        state.add_to_inventory("lamp".to_string());
+        world.move_object("lamp", "inventory");
+
+        let punch = Command::new(Verb::Attack).with_direct("troll");
+        for _ in 0..30 {
+            if state.deaths > 0 {
+                break;
+            }
+            execute(&mut state, &mut world, punch.clone(), &i18n);
+        }
+        assert_eq!(state.deaths, 1);
+        assert!(!state.player_dead);
+        assert_eq!(state.current_room, "forest_1");
+        assert_eq!(state.health, MAX_HEALTH);
+        assert_eq!(state.score, 15);
+        assert!(state.inventory.is_empty());
+        assert_eq!(world.object_location("lamp"), Some("troll_room"));
+
+        state.current_room = "troll_room".to_string();
+        for _ in 0..30 {
+            if state.player_dead {
+                break;
+            }
+            execute(&mut state, &mut world, punch.clone(), &i18n);
+        }
+        assert!(state.player_dead);
+        assert_eq!(state.deaths, 2);
+        assert_eq!(state.current_room, "troll_room");
+    }
+
+    #[test]
+    fn same_seed_replays_the_same_fight() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+        let fight = |seed| {
+            let mut world = World::load_zork1();
+            let mut state = GameState::new(Language::ENGLISH, "troll_room");
+            state.rng = Rng::new(seed);
+            state.add_to_inventory("knife".to_string());
+            world.move_object("knife", "inventory");
+            for _ in 0..4 {
+                execute(
+                    &mut state,
+                    &mut world,
+                    Command::new(Verb::Attack).with_direct("troll"),
+                    &i18n,
+                );
+            }
+            let troll = world.get_creature("troll").expect("troll").hp;
+            (state.health, troll, state.rng)
+        };
+
+        assert_eq!(fight(2024), fight(2024));
+    }
+
+    #[test]
+    fn grating_stays_shut_until_unlocked_with_the_keys() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+        let mut world = World::load_zork1();
+        let mut state = GameState::new(Language::ENGLISH, "grating_room");
+        let mut run = |cmd: Command| execute(&mut state, &mut world, cmd, &i18n).failed;
+
+        assert!(run(Command::new(Verb::Up)));
+        assert!(run(Command::new(Verb::Open).with_direct("grating")));
+        assert!(run(Command::new(Verb::Unlock).with_direct("grating")));
+
+        state.add_to_inventory("keys".to_string());
+        world.move_object("keys", "inventory");
+        state.add_to_inventory("sword".to_string());
+        world.move_object("sword", "inventory");
+        let mut run = |cmd: Command| execute(&mut state, &mut world, cmd, &i18n).failed;
+
+        assert!(run(Command::new(Verb::Unlock)
+            .with_direct("grating")
+            .with_indirect(Preposition::With, "sword")));
+        assert!(!run(Command::new(Verb::Unlock)
+            .with_direct("grating")
+            .with_indirect(Preposition::With, "key")));
+        assert!(!run(Command::new(Verb::Open).with_direct("grating")));
+        assert!(!run(Command::new(Verb::Lock).with_direct("grating")));
+        assert!(!run(Command::new(Verb::Up)));
+
+        assert_eq!(state.current_room, "grating_clearing");
+        assert!(
+            world
+                .get_object("grate")
+                .is_some_and(|grate| !grate.is_locked)
+        );
+    }
+
+    #[test]
+    fn rope_tied_to_the_railing_leads_down_from_the_dome() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+        let mut world = World::load_zork1();
+        let mut state = GameState::new(Language::ENGLISH, "dome_room");
+        state.add_to_inventory("rope".to_string());
+        world.move_object("rope", "inventory");
+        let mut run = |cmd: Command| execute(&mut state, &mut world, cmd, &i18n).failed;
+
+        assert!(run(Command::new(Verb::Down)));
+        assert!(run(Command::new(Verb::Climb).with_direct("rope")));
+        assert!(run(Command::new(Verb::Tie).with_direct("rope")));
+        assert!(!run(Command::new(Verb::Tie)
+            .with_direct("rope")
+            .with_indirect(Preposition::To, "railing")));
+        assert!(run(Command::new(Verb::Take).with_direct("rope")));
+        assert!(!run(Command::new(Verb::Climb).with_direct("rope")));
+
+        assert_eq!(state.current_room, "torch_room");
+        assert!(!state.has_item("rope"));
+        assert_eq!(
+            world
+                .get_object("rope")
+                .and_then(|rope| rope.tied_to.as_deref()),
+            Some("railing")
+        );
+    }
+
+    #[test]
+    fn eating_and_drinking_use_up_food_and_water() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+        let mut world = World::load_zork1();
+        let mut state = GameState::new(Language::ENGLISH, "kitchen");
+        for id in ["bottle", "sandwich_bag"] {
+            state.add_to_inventory(id.to_string());
+            world.move_object(id, "inventory");
+        }
+        let mut run = |cmd: Command| execute(&mut state, &mut world, cmd, &i18n).failed;
+
+        assert!(run(Command::new(Verb::Eat).with_direct("bottle")));
+        assert!(run(Command::new(Verb::Drink).with_direct("water")));
+        assert!(!run(Command::new(Verb::Open).with_direct("bottle")));
+        assert!(!run(Command::new(Verb::Drink).with_direct("water")));
+        assert!(!run(Command::new(Verb::Open).with_direct("sack")));
+        assert!(!run(Command::new(Verb::Eat).with_direct("lunch")));
+        assert!(run(Command::new(Verb::Eat).with_direct("lunch")));
+
+        for id in ["water", "lunch"] {
+            assert_eq!(world.object_location(id), Some(NOWHERE));
+        }
+        assert!(
+            world
+                .get_object("bottle")
+                .is_some_and(|b| b.contents.is_empty())
+        );
+    }
+
+    #[test]
+    fn climbing_the_tree_goes_up() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+        let mut world = World::load_zork1();
+        let mut state = GameState::new(Language::ENGLISH, "path");
+
+        execute(&mut state, &mut world, Command::new(Verb::Climb), &i18n);
+        assert_eq!(state.current_room, "up_a_tree");
+        let outcome = execute(&mut state, &mut world, Command::new(Verb::Climb), &i18n);
+        assert!(outcome.failed);
+    }
+
     #[test]
     fn cyclops_can_be_attacked() {
-        let i18n = I18n::load(Language::English).expect("translation should load");
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
         let mut world = World::load_zork1();
-        let mut state = GameState::new(Language::English, "cyclops_room");
+        let mut state = GameState::new(Language::ENGLISH, "cyclops_room");
+        state.rng = Rng::new(3);
         state.add_to_inventory("sword".to_string());
         world.move_object("sword", "inventory");
 
@@ -1042,10 +2720,7 @@ mod tests {
         execute(
             &mut state,
             &mut world,
-            Command {
-                verb: Verb::Attack,
-                object: Some("cyclops".to_string()),
-            },
+            Command::new(Verb::Attack).with_direct("cyclops"),
             &i18n,
         );
 
@@ -1054,116 +2729,328 @@ mod tests {
     }
 
     #[test]
-    fn trap_door_blocks_cellar_exit_until_opened() {
-        let i18n = I18n::load(Language::English).expect("translation should load");
+    fn diagonal_exits_are_reachable() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
         let mut world = World::load_zork1();
-        let mut state = GameState::new(Language::English, "living_room");
+        let mut state = GameState::new(Language::ENGLISH, "west_of_house");
+
+        for (verb, room) in [
+            (Verb::NorthEast, "north_of_house"),
+            (Verb::SouthEast, "east_of_house"),
+            (Verb::SouthWest, "south_of_house"),
+            (Verb::NorthWest, "west_of_house"),
+        ] {
+            execute(&mut state, &mut world, Command::new(verb), &i18n);
+            assert_eq!(state.current_room, room);
+        }
+    }
 
-        assert_eq!(state.current_room, "living_room");
+    #[test]
+    fn all_except_and_lists_move_each_object() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+        let parser = crate::parser::Parser::new(i18n.words());
+        let mut world = World::load_zork1();
+        let mut state = GameState::new(Language::ENGLISH, "living_room");
+        let run = |state: &mut GameState, world: &mut World, input: &str| {
+            let cmd = parser.parse(input).expect("command should parse");
+            execute(state, world, cmd, &i18n)
+        };
+
+        assert!(!run(&mut state, &mut world, "take all except lamp").failed);
+        assert!(state.has_item("sword"));
+        assert!(!state.has_item("lamp"));
+        assert!(!state.has_item("rug"));
+
+        assert!(!run(&mut state, &mut world, "take sword and the lamp").failed);
+        assert!(state.has_item("lamp"));
+
+        assert!(!run(&mut state, &mut world, "drop all but sword").failed);
+        assert_eq!(state.inventory, vec!["sword".to_string()]);
+        assert_eq!(world.object_location("lamp"), Some("living_room"));
+
+        state.current_room = "west_of_house".to_string();
+        run(&mut state, &mut world, "open mailbox");
+        assert!(!run(&mut state, &mut world, "put sword and lamp in mailbox").failed);
+        assert!(state.inventory.is_empty());
+        assert_eq!(world.object_location("sword"), Some("mailbox"));
+        assert!(run(&mut state, &mut world, "drop all").failed);
+    }
 
-        execute(
+    #[test]
+    fn lamp_dims_and_burns_out_on_schedule() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+        let mut world = World::load_zork1();
+        let mut state = GameState::new(Language::ENGLISH, "west_of_house");
+        state.add_to_inventory("lamp".to_string());
+        world.move_object("lamp", "inventory");
+        let fuel = |world: &World| world.get_object("lamp").and_then(|lamp| lamp.fuel);
+        let lit = |world: &World| world.get_object("lamp").is_some_and(|lamp| lamp.is_lit);
+        world.get_object_mut("lamp").expect("lamp").fuel = Some(16);
+
+        let outcome = execute(
             &mut state,
             &mut world,
-            Command {
-                verb: Verb::Down,
-                object: None,
-            },
+            Command::new(Verb::Use).with_direct("lamp"),
             &i18n,
         );
+        assert!(!outcome.failed);
+        assert!(lit(&world));
+        assert_eq!(fuel(&world), Some(15));
 
-        assert_eq!(state.current_room, "living_room");
+        let saved = SaveFile::capture(&state, &world);
+        let json = serde_json::to_string(&saved).expect("save should serialize");
+        let restored = SaveFile::parse(&json).expect("save should parse");
+        assert!(restored.state.timers.is_active(light::DAEMON));
+
+        execute(&mut state, &mut world, Command::new(Verb::Help), &i18n);
+        assert_eq!(fuel(&world), Some(15));
+
+        for _ in 0..14 {
+            execute(&mut state, &mut world, Command::new(Verb::Look), &i18n);
+        }
+        assert!(lit(&world));
+
+        execute(&mut state, &mut world, Command::new(Verb::Look), &i18n);
+        assert!(!lit(&world));
+        assert!(!state.timers.is_active(light::DAEMON));
+
+        let outcome = execute(
+            &mut state,
+            &mut world,
+            Command::new(Verb::Light).with_direct("lamp"),
+            &i18n,
+        );
+        assert!(outcome.failed);
+        assert!(!lit(&world));
+    }
+
+    #[test]
+    fn a_lit_lamp_lights_the_room_it_is_left_in() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+        let mut world = World::load_zork1();
+        let mut state = GameState::new(Language::ENGLISH, "cellar");
+        let mut run = |cmd: Command| execute(&mut state, &mut world, cmd, &i18n).failed;
+
+        assert!(run(Command::new(Verb::Light).with_direct("lamp")));
+        assert!(run(Command::new(Verb::Up)));
+        state.current_room = "living_room".to_string();
+        let mut run = |cmd: Command| execute(&mut state, &mut world, cmd, &i18n).failed;
+        assert!(!run(Command::new(Verb::Light).with_direct("lamp")));
+        assert!(!run(Command::new(Verb::Take).with_direct("lamp")));
+        state.current_room = "cellar".to_string();
+        assert!(light::has_light(&state, &world));
+
+        let mut run = |cmd: Command| execute(&mut state, &mut world, cmd, &i18n).failed;
+        assert!(!run(Command::new(Verb::Drop).with_direct("lamp")));
+        assert!(light::has_light(&state, &world));
+        state.current_room = "living_room".to_string();
+        assert!(!light::has_light(&state, &world));
+    }
+
+    #[test]
+    fn candles_are_lit_from_a_struck_match() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+        let mut world = World::load_zork1();
+        let mut state = GameState::new(Language::ENGLISH, "south_temple");
+        state.add_to_inventory("match".to_string());
+        world.move_object("match", "inventory");
+        let mut run = |cmd: Command| execute(&mut state, &mut world, cmd, &i18n).failed;
+
+        assert!(run(Command::new(Verb::Light).with_direct("candles")));
+        assert!(!run(Command::new(Verb::Light).with_direct("match")));
+        assert!(!run(Command::new(Verb::Light)
+            .with_direct("candles")
+            .with_indirect(Preposition::With, "match")));
+        assert!(!run(Command::new(Verb::Look)));
+        assert!(!run(Command::new(Verb::Extinguish).with_direct("candles")));
+        assert!(run(Command::new(Verb::Extinguish).with_direct("match")));
+
+        let matchbook = world.get_object("match").expect("matchbook");
+        assert!(!matchbook.is_lit);
+        assert_eq!(matchbook.fuel, Some(light::MATCHES - 1));
+        let candles = world.get_object("candles").expect("candles");
+        assert!(!candles.is_lit);
+        assert_eq!(candles.fuel, Some(light::CANDLE_LIFE - 2));
+        assert!(!state.timers.is_active(light::MATCH_FUSE));
+    }
+
+    #[test]
+    fn lingering_in_the_dark_feeds_the_grue() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+        let mut world = World::load_zork1();
+        let mut state = GameState::new(Language::ENGLISH, "living_room");
+        world
+            .get_object_mut("trap_door")
+            .expect("trap door")
+            .is_open = true;
+
+        execute(&mut state, &mut world, Command::new(Verb::Down), &i18n);
+        assert_eq!(state.current_room, "cellar");
+        assert_eq!(state.dark_turns, 1);
+        assert!(!state.player_dead);
+
+        for _ in 0..20 {
+            if state.player_dead {
+                break;
+            }
+            execute(&mut state, &mut world, Command::new(Verb::Look), &i18n);
+        }
+        assert_eq!(state.deaths, 1);
+        assert!(!state.player_dead);
+        assert_eq!(state.current_room, "forest_1");
+    }
+
+    #[test]
+    fn death_is_final_where_the_world_names_no_resurrection_room() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+        let mut world = World::load_zork3();
+        let mut state = GameState::new(Language::ENGLISH, "cp_ante");
+        state.game = Some(GameChoice::Zork3);
+        assert_eq!(world.resurrection_room, None);
+
+        player_dies(&mut state, &mut world, &i18n);
+        assert!(state.player_dead);
+        assert_eq!(state.current_room, "cp_ante");
+    }
+
+    #[test]
+    fn a_light_keeps_the_grue_away() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+        let mut world = World::load_zork1();
+        let mut state = GameState::new(Language::ENGLISH, "cellar");
+        state.add_to_inventory("torch".to_string());
+        world.move_object("torch", "inventory");
+
+        for _ in 0..50 {
+            execute(&mut state, &mut world, Command::new(Verb::Look), &i18n);
+        }
+        assert!(!state.player_dead);
+        assert_eq!(state.dark_turns, 0);
+    }
+
+    #[test]
+    fn a_lamp_in_an_open_sack_still_lights_the_way() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+        let mut world = World::load_zork1();
+        let mut state = GameState::new(Language::ENGLISH, "cellar");
+        world.add_object(
+            Object::new("sack", "sack")
+                .openable()
+                .open()
+                .with_content("lamp"),
+            "inventory",
+        );
+        state.add_to_inventory("sack".to_string());
+        world.move_object("lamp", "sack");
+        world.get_object_mut("lamp").expect("lamp").is_lit = true;
+
+        execute(&mut state, &mut world, Command::new(Verb::Look), &i18n);
+        assert_eq!(state.dark_turns, 0);
 
         execute(
             &mut state,
             &mut world,
-            Command {
-                verb: Verb::Open,
-                object: Some("trap door".to_string()),
-            },
+            Command::new(Verb::Drop).with_direct("sack"),
             &i18n,
         );
+        assert_eq!(world.object_location("sack"), Some("cellar"));
+        assert_eq!(state.dark_turns, 0);
+
+        world.get_object_mut("sack").expect("sack").is_open = false;
+        execute(&mut state, &mut world, Command::new(Verb::Look), &i18n);
+        assert_eq!(state.dark_turns, 1);
+    }
+
+    #[test]
+    fn legacy_lamp_flag_moves_onto_the_lamp() {
+        let mut legacy = serde_json::to_value(GameState::new(Language::ENGLISH, "cellar"))
+            .expect("state should serialize");
+        legacy["lamp_lit"] = true.into();
+        legacy["lamp_turns"] = 30.into();
+        let save = SaveFile::parse(&legacy.to_string()).expect("legacy save should parse");
+
+        let mut world = World::load_zork1();
+        let mut state = GameState::new(Language::ENGLISH, "west_of_house");
+        save.apply(&mut state, &mut world);
+
+        let lamp = world.get_object("lamp").expect("lamp");
+        assert!(lamp.is_lit);
+        assert_eq!(lamp.fuel, Some(light::LAMP_LIFE - 30));
+        assert!(state.timers.is_active(light::DAEMON));
+        assert_eq!(world.object_location("lamp"), Some("living_room"));
+    }
+
+    #[test]
+    fn trap_door_blocks_cellar_exit_until_opened() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+        let mut world = World::load_zork1();
+        let mut state = GameState::new(Language::ENGLISH, "living_room");
+
+        assert_eq!(state.current_room, "living_room");
+
+        execute(&mut state, &mut world, Command::new(Verb::Down), &i18n);
+
+        assert_eq!(state.current_room, "living_room");
 
         execute(
             &mut state,
             &mut world,
-            Command {
-                verb: Verb::Down,
-                object: None,
-            },
+            Command::new(Verb::Open).with_direct("trap door"),
             &i18n,
         );
 
+        execute(&mut state, &mut world, Command::new(Verb::Down), &i18n);
+
         assert_eq!(state.current_room, "cellar");
     }
 
     #[test]
     fn dark_room_shows_darkness_without_light() {
-        let i18n = I18n::load(Language::English).expect("translation should load");
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
         let mut world = World::load_zork1();
-        let mut state = GameState::new(Language::English, "living_room");
+        let mut state = GameState::new(Language::ENGLISH, "living_room");
 
         let trap_door = world
             .get_object_mut("trap_door")
             .expect("trap_door should exist");
         trap_door.is_open = true;
 
-        execute(
-            &mut state,
-            &mut world,
-            Command {
-                verb: Verb::Down,
-                object: None,
-            },
-            &i18n,
-        );
+        execute(&mut state, &mut world, Command::new(Verb::Down), &i18n);
 
         assert_eq!(state.current_room, "cellar");
+        assert!(!light::has_light(&state, &world));
 
-        state.lamp_lit = false;
-
-        execute(
-            &mut state,
-            &mut world,
-            Command {
-                verb: Verb::Look,
-                object: None,
-            },
-            &i18n,
-        );
+        execute(&mut state, &mut world, Command::new(Verb::Look), &i18n);
     }
 
     #[test]
     fn dark_room_visible_with_lamp_lit() {
-        let i18n = I18n::load(Language::English).expect("translation should load");
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
         let mut world = World::load_zork1();
-        let mut state = GameState::new(Language::English, "living_room");
+        let mut state = GameState::new(Language::ENGLISH, "living_room");
 
         let trap_door = world
             .get_object_mut("trap_door")
             .expect("trap_door should exist");
         trap_door.is_open = true;
-        state.lamp_lit = true;
-
         execute(
             &mut state,
             &mut world,
-            Command {
-                verb: Verb::Down,
-                object: None,
-            },
+            Command::new(Verb::Take).with_direct("lamp"),
             &i18n,
         );
-
-        assert_eq!(state.current_room, "cellar");
-
         execute(
             &mut state,
             &mut world,
-            Command {
-                verb: Verb::Look,
-                object: None,
-            },
+            Command::new(Verb::Light).with_direct("lamp"),
             &i18n,
         );
+
+        execute(&mut state, &mut world, Command::new(Verb::Down), &i18n);
+
+        assert_eq!(state.current_room, "cellar");
+        assert!(light::has_light(&state, &world));
+
+        execute(&mut state, &mut world, Command::new(Verb::Look), &i18n);
     }
 }
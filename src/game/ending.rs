@@ -0,0 +1,142 @@
+use crate::game::GameChoice;
+use crate::game::score::TROPHY_CASE;
+use crate::game::state::GameState;
+use crate::game::world::World;
+use crate::i18n::I18n;
+use crate::logging;
+
+/// The Zork I door that opens once every treasure is in the trophy case.
+pub const BARROW_DOOR: &str = "barrow_door";
+const BARROW: &str = "stone_barrow";
+const WIZARD: &str = "wizard";
+const TREASURY: &str = "nirvana";
+
+/// Checks the running game's victory condition after a turn, opening the
+/// Zork I barrow on the way. Sets `state.won` once the game is won.
+pub fn update(state: &mut GameState, world: &mut World, i18n: &I18n) {
+    let Some(game) = state.game else {
+        return;
+    };
+    if state.won || state.player_dead {
+        return;
+    }
+
+    if game == GameChoice::Zork1 && all_treasures_cased(world) && open_barrow(world) {
+        logging::info(format!("ending.barrow_open score={}", state.score));
+        println!("\n{}", i18n.ui().barrow_opens);
+    }
+
+    state.won = match game {
+        GameChoice::Zork1 => {
+            state.current_room == BARROW
+                && world
+                    .get_object(BARROW_DOOR)
+                    .is_some_and(|door| door.is_open)
+        }
+        GameChoice::Zork2 => world.get_creature(WIZARD).is_some_and(|c| !c.is_alive()),
+        GameChoice::Zork3 => state.current_room == TREASURY,
+    };
+
+    if state.won {
+        logging::info(format!(
+            "ending.won game={} score={} max={} moves={}",
+            game.code(),
+            state.score,
+            world.max_score,
+            state.moves
+        ));
+    }
+}
+
+/// The closing text for `game`, followed by the final score and rank.
+pub fn victory_text(state: &GameState, world: &World, i18n: &I18n, game: GameChoice) -> String {
+    let ui = i18n.ui();
+    let story = match game {
+        GameChoice::Zork1 => &ui.victory_zork1,
+        GameChoice::Zork2 => &ui.victory_zork2,
+        GameChoice::Zork3 => &ui.victory_zork3,
+    };
+    let max = world.max_score;
+    let summary = i18n.format(
+        &ui.final_score,
+        &[
+            ("score", &state.score.to_string()),
+            ("max", &max.to_string()),
+            ("moves", &state.moves.to_string()),
+            ("rank", i18n.rank_title(state.score, max)),
+        ],
+    );
+    format!("{}\n\n{}\n\n{}", story, ui.victory_title, summary)
+}
+
+/// True once every treasure in `World::case_treasures` is in the trophy
+/// case.
+fn all_treasures_cased(world: &World) -> bool {
+    world
+        .case_treasures
+        .iter()
+        .all(|id| world.object_location(id) == Some(TROPHY_CASE))
+}
+
+/// Opens the barrow door; false if it was already open.
+fn open_barrow(world: &mut World) -> bool {
+    match world.get_object_mut(BARROW_DOOR) {
+        Some(door) if !door.is_open => {
+            door.is_open = true;
+            true
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::actions::execute;
+    use crate::i18n::Language;
+    use crate::parser::{Command, Verb};
+
+    #[test]
+    fn casing_every_treasure_opens_the_barrow_and_wins() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+        let mut world = World::load_zork1();
+        let mut state = GameState::new(Language::ENGLISH, "west_of_house");
+        state.game = Some(GameChoice::Zork1);
+
+        execute(&mut state, &mut world, Command::new(Verb::SouthWest), &i18n);
+        assert_eq!(state.current_room, "west_of_house");
+
+        // The emerald is inside the unported buoy; the barrow opens without
+        // it.
+        let treasures = world.case_treasures.clone();
+        assert!(!treasures.iter().any(|id| id == "emerald"));
+        for id in treasures {
+            world.move_object(&id, TROPHY_CASE);
+        }
+        execute(&mut state, &mut world, Command::new(Verb::Look), &i18n);
+        assert!(!state.won);
+        assert_eq!(world.object_location("emerald"), Some("buoy"));
+
+        execute(&mut state, &mut world, Command::new(Verb::SouthWest), &i18n);
+        assert_eq!(state.current_room, BARROW);
+        assert!(state.won);
+    }
+
+    #[test]
+    fn the_wizards_demise_ends_zork_two() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+        let mut world = World::load_zork2();
+        let mut state = GameState::new(Language::ENGLISH, "wizards_workshop");
+        state.game = Some(GameChoice::Zork2);
+
+        world
+            .get_creature_mut(WIZARD)
+            .expect("the wizard should exist")
+            .take_damage(100);
+        execute(&mut state, &mut world, Command::new(Verb::Look), &i18n);
+        assert!(state.won);
+        let text = victory_text(&state, &world, &i18n, GameChoice::Zork2);
+        assert!(text.contains("You have won"));
+        assert!(text.contains("rank of Beginner"));
+    }
+}
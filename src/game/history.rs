@@ -0,0 +1,81 @@
+use crate::game::state::GameState;
+use crate::game::world::{World, WorldSnapshot};
+use std::collections::VecDeque;
+
+/// How many turns `undo` can walk back.
+pub const UNDO_DEPTH: usize = 10;
+
+/// Bounded journal of pre-turn snapshots, newest last.
+pub struct TurnHistory {
+    entries: VecDeque<(GameState, WorldSnapshot)>,
+    limit: usize,
+}
+
+impl TurnHistory {
+    pub fn new(limit: usize) -> Self {
+        TurnHistory {
+            entries: VecDeque::with_capacity(limit),
+            limit,
+        }
+    }
+
+    pub fn record(&mut self, state: &GameState, world: &World) {
+        if self.limit == 0 {
+            return;
+        }
+        if self.entries.len() == self.limit {
+            self.entries.pop_front();
+        }
+        self.entries.push_back((state.clone(), world.snapshot()));
+    }
+
+    /// Rolls `state` and `world` back to the most recent snapshot.
+    /// Returns false when there is nothing left to undo.
+    pub fn undo(&mut self, state: &mut GameState, world: &mut World) -> bool {
+        let Some((saved_state, snapshot)) = self.entries.pop_back() else {
+            return false;
+        };
+        world.restore(&snapshot);
+        *state = saved_state;
+        true
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl Default for TurnHistory {
+    fn default() -> Self {
+        Self::new(UNDO_DEPTH)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::Language;
+
+    #[test]
+    fn history_is_bounded_and_pops_newest_first() {
+        let mut world = World::load_zork1();
+        let mut state = GameState::new(Language::ENGLISH, "west_of_house");
+        let mut history = TurnHistory::new(2);
+
+        for room in ["north_of_house", "path", "up_a_tree"] {
+            history.record(&state, &world);
+            state.move_to(room);
+        }
+        assert_eq!(history.len(), 2);
+
+        assert!(history.undo(&mut state, &mut world));
+        assert_eq!(state.current_room, "path");
+        assert!(history.undo(&mut state, &mut world));
+        assert_eq!(state.current_room, "north_of_house");
+        assert!(!history.undo(&mut state, &mut world));
+    }
+}
@@ -0,0 +1,202 @@
+use crate::game::actions::player_dies;
+use crate::game::state::GameState;
+use crate::game::world::{LightKind, Object, World};
+use crate::i18n::{I18n, UiStrings};
+use crate::logging;
+
+/// Daemon burning down whatever is lit and has fuel.
+pub const DAEMON: &str = "light";
+
+/// Fuse putting out the match that was struck last.
+pub const MATCH_FUSE: &str = "match";
+
+/// Turns a fully charged lamp burns before going out for good.
+pub const LAMP_LIFE: u32 = 330;
+
+/// Turns the candles burn before they are gone.
+pub const CANDLE_LIFE: u32 = 40;
+
+/// Matches in a fresh matchbook.
+pub const MATCHES: u32 = 6;
+
+/// Turns a struck match burns.
+pub const MATCH_BURN: u32 = 2;
+
+/// Percent chance per dark turn, after the first, that the grue strikes.
+const GRUE_ODDS: u32 = 25;
+
+/// The grue never becomes a certainty.
+const GRUE_MAX_ODDS: u32 = 90;
+
+/// Remaining lamp turns at which the player is warned it is dimming.
+const LAMP_WARNINGS: [u32; 3] = [100, 70, 15];
+
+/// Remaining candle turns at which the player is warned they are shrinking.
+const CANDLE_WARNINGS: [u32; 2] = [20, 5];
+
+/// Whether anything lit is in the player's room or hands.
+pub fn has_light(state: &GameState, world: &World) -> bool {
+    world
+        .objects
+        .values()
+        .any(|obj| obj.is_lit && is_present(state, world, &obj.id))
+}
+
+/// Whether the player can see `object_id`: carried or lying in their room,
+/// directly or inside open containers there, as for `visible_objects`.
+pub fn is_present(state: &GameState, world: &World, object_id: &str) -> bool {
+    let mut id = object_id;
+    // Bounded so a container cycle in a broken world cannot hang us.
+    for _ in 0..=world.objects.len() {
+        if state.has_item(id) {
+            return true;
+        }
+        let Some(location) = world.object_location(id) else {
+            return false;
+        };
+        if location == state.current_room {
+            return true;
+        }
+        match world.get_object(location) {
+            Some(container) if container.is_openable && container.is_open => id = location,
+            _ => return false,
+        }
+    }
+    false
+}
+
+/// Keeps the burn daemon running while something with fuel is lit. Called
+/// when a light is lit and after restoring a save.
+pub fn start_daemon(state: &mut GameState, world: &World) {
+    if world.objects.values().any(burns_fuel) {
+        state.timers.start_daemon(DAEMON);
+    }
+}
+
+/// The lines a light of `kind` prints when lit, put out, burning low and
+/// burnt out.
+pub fn texts(ui: &UiStrings, kind: LightKind) -> [&String; 4] {
+    match kind {
+        LightKind::Lamp => [&ui.lamp_on, &ui.lamp_off, &ui.lamp_dim, &ui.lamp_out],
+        LightKind::Candle => [
+            &ui.candles_on,
+            &ui.candles_off,
+            &ui.candles_dim,
+            &ui.candles_out,
+        ],
+        LightKind::Match => [&ui.match_on, &ui.match_off, &ui.match_out, &ui.match_out],
+    }
+}
+
+/// One turn of burning: every lit lamp and candle loses a turn of fuel,
+/// warns the player as it runs low and goes out when it is spent.
+pub fn burn(state: &mut GameState, world: &mut World, i18n: &I18n) {
+    let mut burning: Vec<String> = world
+        .objects
+        .values()
+        .filter(|obj| burns_fuel(obj))
+        .map(|obj| obj.id.clone())
+        .collect();
+    burning.sort();
+
+    let lit_before = has_light(state, world);
+    for id in burning {
+        let present = is_present(state, world, &id);
+        let Some(obj) = world.get_object_mut(&id) else {
+            continue;
+        };
+        let (Some(kind), Some(fuel)) = (obj.light, obj.fuel) else {
+            continue;
+        };
+        let remaining = fuel.saturating_sub(1);
+        obj.fuel = Some(remaining);
+        let [_, _, dim, out] = texts(i18n.ui(), kind);
+
+        if remaining == 0 {
+            obj.is_lit = false;
+            logging::info(format!(
+                "light.out object={} room={}",
+                id, state.current_room
+            ));
+            if present {
+                println!("\n{}", out);
+            }
+        } else if warnings(kind).contains(&remaining) {
+            logging::info(format!("light.dim object={} remaining={}", id, remaining));
+            if present {
+                println!("\n{}", dim);
+            }
+        }
+    }
+
+    if !world.objects.values().any(burns_fuel) {
+        state.timers.cancel(DAEMON);
+    }
+    report_darkness(state, world, i18n, lit_before);
+}
+
+/// The grue rule, run after every turn that takes time. Arriving in the dark
+/// is safe and only warns; each further turn there is likelier to be the
+/// last.
+pub fn lurking_grue(state: &mut GameState, world: &mut World, i18n: &I18n) {
+    if state.player_dead || !world.get_room(&state.current_room).is_dark || has_light(state, world)
+    {
+        state.dark_turns = 0;
+        return;
+    }
+
+    state.dark_turns += 1;
+    let odds = (GRUE_ODDS * (state.dark_turns - 1)).min(GRUE_MAX_ODDS);
+    if odds == 0 || !state.rng.chance(odds) {
+        return;
+    }
+
+    logging::info(format!(
+        "grue.eats room={} dark_turns={}",
+        state.current_room, state.dark_turns
+    ));
+    state.dark_turns = 0;
+    println!("\n{}", i18n.ui().grue_eats);
+    player_dies(state, world, i18n);
+}
+
+/// The struck match has burned down to the player's fingers.
+pub fn match_burns_out(state: &mut GameState, world: &mut World, i18n: &I18n) {
+    let lit_before = has_light(state, world);
+    let mut spent = Vec::new();
+    for obj in world.objects.values_mut() {
+        if obj.light == Some(LightKind::Match) && obj.is_lit {
+            obj.is_lit = false;
+            spent.push(obj.id.clone());
+        }
+    }
+
+    for id in spent {
+        logging::info(format!("light.match_out object={}", id));
+        if is_present(state, world, &id) {
+            println!("\n{}", i18n.ui().match_out);
+        }
+    }
+    report_darkness(state, world, i18n, lit_before);
+}
+
+fn burns_fuel(obj: &Object) -> bool {
+    obj.is_lit
+        && obj.fuel.is_some()
+        && matches!(obj.light, Some(LightKind::Lamp | LightKind::Candle))
+}
+
+fn warnings(kind: LightKind) -> &'static [u32] {
+    match kind {
+        LightKind::Lamp => &LAMP_WARNINGS,
+        LightKind::Candle => &CANDLE_WARNINGS,
+        LightKind::Match => &[],
+    }
+}
+
+/// Tells the player the room went dark if their last light just went out.
+fn report_darkness(state: &GameState, world: &World, i18n: &I18n, lit_before: bool) {
+    if lit_before && world.get_room(&state.current_room).is_dark && !has_light(state, world) {
+        println!("{}", i18n.ui().darkness);
+    }
+}
@@ -1,55 +1,141 @@
 pub mod actions;
+pub mod ending;
+pub mod history;
+pub mod light;
+pub mod loader;
+pub mod npc;
+pub mod rng;
+pub mod save;
+pub mod score;
 pub mod state;
+pub mod timers;
+pub mod validate;
 pub mod world;
 
 use crate::i18n::I18n;
 use crate::i18n::Language;
 use crate::logging;
-use crate::parser::Parser;
+use crate::parser::{Command, Parser, ParserContext, Selection, Verb};
+use actions::{Ambiguity, Outcome};
+use history::TurnHistory;
+use rng::Rng;
+use serde::{Deserialize, Serialize};
 use state::GameState;
 use world::World;
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum GameChoice {
+    #[serde(rename = "zork1")]
     Zork1,
+    #[serde(rename = "zork2")]
     Zork2,
+    #[serde(rename = "zork3")]
     Zork3,
 }
 
+impl GameChoice {
+    pub const ALL: [GameChoice; 3] = [GameChoice::Zork1, GameChoice::Zork2, GameChoice::Zork3];
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        GameChoice::ALL
+            .into_iter()
+            .find(|choice| choice.code() == code)
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            GameChoice::Zork1 => "zork1",
+            GameChoice::Zork2 => "zork2",
+            GameChoice::Zork3 => "zork3",
+        }
+    }
+
+    pub fn short_title(&self) -> &'static str {
+        match self {
+            GameChoice::Zork1 => "Zork I",
+            GameChoice::Zork2 => "Zork II",
+            GameChoice::Zork3 => "Zork III",
+        }
+    }
+}
+
 pub struct Game {
     state: GameState,
     world: World,
     parser: Parser,
+    context: ParserContext,
     i18n: I18n,
     choice: GameChoice,
+    /// Seed for new games and restarts; `None` picks one from the clock.
+    seed: Option<u64>,
+    history: TurnHistory,
+    /// A command waiting for the answer to "Which do you mean?".
+    pending: Option<(Command, Ambiguity)>,
 }
 
 impl Game {
     pub fn new(i18n: I18n, choice: GameChoice) -> Self {
-        let lang = i18n.language();
-        let (world, start_room) = match choice {
-            GameChoice::Zork1 => (World::load_zork1(), "west_of_house"),
-            GameChoice::Zork2 => (World::load_zork2(), "inside_barrow"),
-            GameChoice::Zork3 => (World::load_zork3(), "cp_ante"),
-        };
+        let (state, world) = Self::fresh(i18n.language(), choice, None);
+
+        Game {
+            state,
+            world,
+            parser: Parser::new(i18n.words()),
+            context: ParserContext::default(),
+            i18n,
+            choice,
+            seed: None,
+            history: TurnHistory::default(),
+            pending: None,
+        }
+    }
+
+    /// Fixes the seed of the game's random numbers, making fights and the
+    /// thief's wanderings reproducible.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self.state.rng = Rng::new(seed);
+        logging::info(format!("rng.seed seed={} source=fixed", seed));
+        self
+    }
+
+    fn fresh(lang: &Language, choice: GameChoice, seed: Option<u64>) -> (GameState, World) {
+        let seed = seed.unwrap_or_else(Rng::entropy_seed);
+        let world = World::load(choice);
+        let start_room = world.start_room.as_str();
 
         logging::info(format!(
-            "game.new choice={:?} lang={} rooms={} objects={} creatures={} start_room={}",
+            "game.new choice={:?} lang={} rooms={} objects={} creatures={} start_room={} seed={}",
             choice,
             lang.code(),
             world.rooms.len(),
             world.objects.len(),
             world.creatures.len(),
-            start_room
+            start_room,
+            seed
         ));
 
-        Game {
-            state: GameState::new(lang, start_room),
-            world,
-            parser: Parser::new(lang),
-            i18n,
-            choice,
-        }
+        let mut state = GameState::new(lang.clone(), start_room);
+        state.game = Some(choice);
+        state.rng = Rng::new(seed);
+        npc::start_daemons(&mut state, &world);
+        (state, world)
+    }
+
+    /// Starts the same game over from its first room.
+    fn restart(&mut self) {
+        logging::info(format!(
+            "game.restart choice={:?} deaths={} score={} moves={}",
+            self.choice, self.state.deaths, self.state.score, self.state.moves
+        ));
+        (self.state, self.world) = Self::fresh(self.i18n.language(), self.choice, self.seed);
+        self.context.clear();
+        self.history = TurnHistory::default();
+        self.pending = None;
+
+        println!();
+        self.show_intro();
+        self.show_room();
     }
 
     pub fn run(&mut self) {
@@ -69,26 +155,67 @@ impl Game {
                 self.state.current_room, input
             ));
 
-            if self.is_quit(&input) {
+            let keep_playing = self.run_line(&input)
+                && (!self.state.player_dead || self.after_death())
+                && (!self.state.won || self.after_victory());
+            if !keep_playing {
+                break;
+            }
+        }
+    }
+
+    /// Runs every clause of one input line in order. The chain stops at the
+    /// first command that fails or ends the game. Returns false on quit.
+    fn run_line(&mut self, input: &str) -> bool {
+        if let Some((cmd, ambiguity)) = self.pending.take()
+            && let Some(cmd) = self.answer(cmd, &ambiguity, input)
+        {
+            self.execute(cmd);
+            return true;
+        }
+
+        let clauses = self.parser.split_clauses(input);
+        let total = clauses.len();
+
+        for (index, clause) in clauses.iter().enumerate() {
+            if self.is_quit(clause) {
                 logging::info("command.quit");
                 self.show_goodbye();
-                break;
+                return false;
             }
 
-            if let Some(cmd) = self.parser.parse(&input) {
+            let Some(cmd) = self.parser.parse_in_context(clause, &self.context) else {
+                logging::warn(format!("command.parse.none input={}", clause));
+                break;
+            };
+
+            logging::info(format!(
+                "command.parsed verb={:?} direct={:?} prep={:?} indirect={:?}",
+                cmd.verb, cmd.direct, cmd.preposition, cmd.indirect
+            ));
+
+            let outcome = self.execute(cmd);
+            let game_over = self.state.player_dead || self.state.won;
+            if (outcome.failed || game_over) && index + 1 < total {
                 logging::info(format!(
-                    "command.parsed verb={:?} object={:?}",
-                    cmd.verb, cmd.object
+                    "command.chain.stop clause={} of={} dead={} won={}",
+                    index + 1,
+                    total,
+                    self.state.player_dead,
+                    self.state.won
                 ));
-                self.execute(cmd);
-            } else {
-                logging::warn(format!("command.parse.none input={}", input));
+                break;
             }
         }
+
+        true
     }
 
     fn show_intro(&self) {
-        let title = localized_game_title(self.choice, self.i18n.language());
+        let title = self
+            .i18n
+            .game(self.choice.code())
+            .map_or(self.choice.short_title(), |game| game.title.as_str());
         println!("{}", self.i18n.ui().welcome);
         println!("\n{}\n", title);
     }
@@ -111,15 +238,72 @@ impl Game {
         io::stdout().flush().unwrap();
 
         let mut input = String::new();
-        io::stdin().read_line(&mut input).unwrap();
+        // End of input quits, so a piped script cannot leave a prompt spinning.
+        if io::stdin().read_line(&mut input).unwrap() == 0 {
+            return "quit".to_string();
+        }
         input.trim().to_lowercase()
     }
 
     fn is_quit(&self, input: &str) -> bool {
-        match self.i18n.language() {
-            Language::English => matches!(input, "quit" | "exit" | "q"),
-            Language::Italian => matches!(input, "quit" | "exit" | "q" | "esci" | "fine"),
-            Language::Spanish => matches!(input, "quit" | "exit" | "q" | "salir" | "fin"),
+        self.i18n.words().is_quit(input)
+    }
+
+    fn is_restart(&self, input: &str) -> bool {
+        self.i18n.words().is_restart(input)
+    }
+
+    /// Asks a dead player whether to restart, restore a save or quit, until
+    /// one of them works. Returns false on quit.
+    fn after_death(&mut self) -> bool {
+        let ui = self.i18n.ui();
+        let (question, options) = (ui.restart.clone(), ui.death_options.clone());
+
+        loop {
+            println!("\n{}", question);
+            println!("{}", options);
+            let input = self.read_input();
+
+            if self.is_quit(&input) {
+                logging::info("command.quit dead=true");
+                self.show_goodbye();
+                return false;
+            }
+            if self.is_restart(&input) {
+                self.restart();
+                return true;
+            }
+            if let Some(cmd) = self.parser.parse(&input)
+                && matches!(cmd.verb, Verb::Restore)
+            {
+                self.execute(cmd);
+                if !self.state.player_dead {
+                    return true;
+                }
+            }
+        }
+    }
+
+    /// Shows the end screen of a won game and asks whether to play again.
+    /// Returns false on quit.
+    fn after_victory(&mut self) -> bool {
+        let text = ending::victory_text(&self.state, &self.world, &self.i18n, self.choice);
+        println!("\n{}", text);
+        let options = self.i18n.ui().victory_options.clone();
+
+        loop {
+            println!("\n{}", options);
+            let input = self.read_input();
+
+            if self.is_quit(&input) {
+                logging::info("command.quit won=true");
+                self.show_goodbye();
+                return false;
+            }
+            if self.is_restart(&input) {
+                self.restart();
+                return true;
+            }
         }
     }
 
@@ -127,21 +311,206 @@ impl Game {
         println!("\n{}", self.i18n.ui().goodbye);
     }
 
-    fn execute(&mut self, cmd: crate::parser::Command) {
-        actions::execute(&mut self.state, &mut self.world, cmd, &self.i18n);
+    /// Applies a reply to a "Which do you mean?" question to the command
+    /// that asked it. A reply that reads as a command of its own, or names
+    /// none of the candidates, abandons the question.
+    fn answer(&self, mut cmd: Command, ambiguity: &Ambiguity, reply: &str) -> Option<Command> {
+        if !matches!(self.parser.parse(reply)?.verb, Verb::Unknown(_)) {
+            return None;
+        }
+
+        let noun = self.parser.parse_noun(reply)?;
+        let id = actions::choose_candidate(&self.world, &self.i18n, &ambiguity.candidates, &noun)?;
+        logging::info(format!(
+            "resolve.answer phrase={} chosen={}",
+            ambiguity.phrase, id
+        ));
+
+        let phrase = Some(ambiguity.phrase.as_str());
+        if cmd.direct.as_deref() == phrase {
+            cmd.direct = Some(id);
+        } else if cmd.indirect.as_deref() == phrase {
+            cmd.indirect = Some(id);
+        } else if let Some(Selection::Items(items)) = &mut cmd.selection {
+            for item in items.iter_mut().filter(|item| **item == ambiguity.phrase) {
+                *item = id.clone();
+            }
+        }
+        Some(cmd)
+    }
+
+    fn execute(&mut self, cmd: Command) -> Outcome {
+        if matches!(cmd.verb, Verb::Undo) {
+            return self.undo();
+        }
+
+        if !cmd.verb.is_meta() && !matches!(cmd.verb, Verb::Unknown(_)) {
+            self.history.record(&self.state, &self.world);
+        }
+
+        let retry = cmd.clone();
+        let mut outcome = actions::execute(&mut self.state, &mut self.world, cmd, &self.i18n);
+        self.context.remember(&outcome.referents);
+        if let Some(ambiguity) = outcome.ambiguity.take() {
+            self.pending = Some((retry, ambiguity));
+        }
+        outcome
+    }
+
+    fn undo(&mut self) -> Outcome {
+        if self.history.undo(&mut self.state, &mut self.world) {
+            logging::info(format!(
+                "undo.ok room={} moves={} remaining={}",
+                self.state.current_room,
+                self.state.moves,
+                self.history.len()
+            ));
+            self.context.clear();
+            println!("\n{}", self.i18n.ui().undone);
+            self.show_room();
+            Outcome::done()
+        } else {
+            logging::warn("undo.empty");
+            println!("\n{}", self.i18n.ui().cant_undo);
+            Outcome::failed()
+        }
     }
 }
 
-fn localized_game_title(choice: GameChoice, lang: Language) -> &'static str {
-    match (choice, lang) {
-        (GameChoice::Zork1, Language::English) => "ZORK I: THE GREAT UNDERGROUND EMPIRE",
-        (GameChoice::Zork2, Language::English) => "ZORK II: THE WIZARD OF FROBOZZ",
-        (GameChoice::Zork3, Language::English) => "ZORK III: THE DUNGEON MASTER",
-        (GameChoice::Zork1, Language::Italian) => "ZORK I: IL GRANDE IMPERO SOTTERRANEO",
-        (GameChoice::Zork2, Language::Italian) => "ZORK II: IL MAGO DI FROBOZZ",
-        (GameChoice::Zork3, Language::Italian) => "ZORK III: IL DUNGEON MASTER",
-        (GameChoice::Zork1, Language::Spanish) => "ZORK I: EL GRAN IMPERIO SUBTERRANEO",
-        (GameChoice::Zork2, Language::Spanish) => "ZORK II: EL MAGO DE FROBOZZ",
-        (GameChoice::Zork3, Language::Spanish) => "ZORK III: EL DUNGEON MASTER",
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use world::Object;
+
+    fn command(verb: Verb, object: Option<&str>) -> Command {
+        let cmd = Command::new(verb);
+        match object {
+            Some(object) => cmd.with_direct(object),
+            None => cmd,
+        }
+    }
+
+    #[test]
+    fn undo_reverts_last_world_changing_turn() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+        let mut game = Game::new(i18n, GameChoice::Zork1).with_seed(1);
+        game.state.current_room = "troll_room".to_string();
+        game.state.add_to_inventory("sword".to_string());
+        game.world.move_object("sword", "inventory");
+
+        game.execute(command(Verb::Attack, Some("troll")));
+        assert!(!game.world.get_creature("troll").expect("troll").is_alive());
+
+        game.execute(command(Verb::Help, None));
+        game.execute(command(Verb::Undo, None));
+
+        let troll = game.world.get_creature("troll").expect("troll");
+        assert!(troll.is_alive());
+        assert_eq!(troll.hp, troll.max_hp);
+        assert_eq!(game.world.object_location("axe"), Some("troll"));
+        assert_eq!(game.state.score, 0);
+        assert!(game.state.has_item("sword"));
+
+        game.execute(command(Verb::Undo, None));
+        assert!(game.history.is_empty());
+    }
+
+    #[test]
+    fn zork_three_is_won_by_walking_into_the_treasury() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+        let mut game = Game::new(i18n, GameChoice::Zork3).with_seed(1);
+
+        game.run_line("n. d. n. n. n. n. n. n. n. n. n");
+        assert_eq!(game.state.current_room, "south_corridor");
+        assert!(!game.state.won);
+
+        game.run_line("e. n. s. s");
+        assert_eq!(game.state.current_room, "nirvana");
+        assert!(game.state.won);
+    }
+
+    #[test]
+    fn zork_two_is_won_by_slaying_the_wizard() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+        let mut game = Game::new(i18n, GameChoice::Zork2).with_seed(1);
+
+        game.run_line("take sword. s. s. s. sw. s. sw. sw. sw. sw. s");
+        assert_eq!(game.state.current_room, "wizards_workshop");
+
+        for _ in 0..20 {
+            if game.state.won || game.state.player_dead {
+                break;
+            }
+            game.run_line("kill wizard with sword");
+        }
+        assert!(game.state.won);
+        assert!(!game.state.player_dead);
+    }
+
+    #[test]
+    fn chained_input_runs_in_order_and_stops_on_failure() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+        let mut game = Game::new(i18n, GameChoice::Zork1);
+
+        assert!(game.run_line("n. e, then s"));
+        assert_eq!(game.state.current_room, "south_of_house");
+
+        assert!(game.run_line("take unicorn then w"));
+        assert_eq!(game.state.current_room, "south_of_house");
+
+        assert!(game.run_line("up, w"));
+        assert_eq!(game.state.current_room, "south_of_house");
+
+        assert!(!game.run_line("w then quit"));
+        assert_eq!(game.state.current_room, "west_of_house");
+    }
+
+    #[test]
+    fn pronouns_follow_the_last_referenced_object() {
+        let i18n = I18n::load(Language::ITALIAN).expect("translation should load");
+        let mut game = Game::new(i18n, GameChoice::Zork1);
+        game.state.current_room = "living_room".to_string();
+
+        game.run_line("prendi la spada poi posala");
+        assert!(!game.state.has_item("sword"));
+        assert_eq!(game.world.object_location("sword"), Some("living_room"));
+
+        game.run_line("prendilo");
+        assert!(game.state.has_item("sword"));
+
+        game.run_line("prendi lampada e spada. posali");
+        assert!(game.state.inventory.is_empty());
+    }
+
+    #[test]
+    fn ambiguous_names_ask_and_take_the_reply() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+        let mut game = Game::new(i18n, GameChoice::Zork1);
+        game.world
+            .add_object(Object::new("rusty_key", "rusty key"), "west_of_house");
+        game.world
+            .add_object(Object::new("skeleton_key", "skeleton key"), "west_of_house");
+
+        game.run_line("take key");
+        assert!(game.state.inventory.is_empty());
+        let (_, ambiguity) = game.pending.clone().expect("question should be pending");
+        assert_eq!(ambiguity.candidates, vec!["rusty_key", "skeleton_key"]);
+
+        game.run_line("the rusty one");
+        assert!(game.state.has_item("rusty_key"));
+        assert!(game.pending.is_none());
+
+        game.run_line("take key");
+        assert!(game.state.has_item("skeleton_key"));
+
+        game.run_line("drop key");
+        assert!(game.pending.is_some());
+        game.run_line("north");
+        assert!(game.pending.is_none());
+        assert_eq!(game.state.current_room, "north_of_house");
+        assert_eq!(game.state.inventory.len(), 2);
+
+        game.run_line("drop skeleton key");
+        assert_eq!(game.state.inventory, vec!["rusty_key".to_string()]);
     }
 }
@@ -0,0 +1,278 @@
+use crate::game::actions::{creature_display_line, object_display_name};
+use crate::game::state::GameState;
+use crate::game::world::World;
+use crate::i18n::I18n;
+use crate::logging;
+
+pub const THIEF: &str = "thief";
+
+/// The fuse that sends the thief home with his loot.
+pub const THIEF_STASH: &str = "thief_stash";
+
+/// Where the thief hides what he steals.
+pub const THIEF_LAIR: &str = "treasure_room";
+
+/// Turns the thief carries loot around before taking it home.
+const STASH_DELAY: u32 = 6;
+
+/// Percent chance, each turn, that the thief robs the player he is with or
+/// pockets the treasure lying where he is.
+const STEAL_CHANCE: u32 = 40;
+
+/// Starts the daemons of whichever NPCs this world has. Called for new
+/// games and after restoring saves that predate the scheduler.
+pub fn start_daemons(state: &mut GameState, world: &World) {
+    if world.get_creature(THIEF).is_some_and(|c| c.is_alive()) {
+        state.timers.start_daemon(THIEF);
+    }
+}
+
+/// One turn of the thief: maybe rob the player if he is already with them,
+/// otherwise maybe pocket loose treasure and wander on to another
+/// underground room.
+pub fn thief_daemon(state: &mut GameState, world: &mut World, i18n: &I18n) {
+    if !world.get_creature(THIEF).is_some_and(|c| c.is_alive()) {
+        state.timers.cancel(THIEF);
+        return;
+    }
+    let Some(here) = world.object_location(THIEF).map(str::to_string) else {
+        return;
+    };
+
+    if here == state.current_room {
+        let item = first_valuable(world, state.inventory.iter());
+        let Some(item) = item.filter(|_| state.rng.chance(STEAL_CHANCE)) else {
+            // Nothing taken; he lurks, which gives the player a chance.
+            return;
+        };
+        world.move_object(&item, THIEF);
+        state.remove_from_inventory(&item);
+        logging::info(format!("npc.steal id={} item={} from=player", THIEF, item));
+
+        let name = object_display_name(world, i18n, &item);
+        if let Some(text) = i18n.creature(THIEF).and_then(|c| c.steals.as_deref()) {
+            println!("\n{}", i18n.format(text, &[("obj", &name)]));
+        }
+    } else {
+        let mut loose: Vec<String> = world
+            .objects_in_room(&here)
+            .into_iter()
+            .filter(|obj| obj.valuable && obj.takeable)
+            .map(|obj| obj.id.clone())
+            .collect();
+        if !loose.is_empty() && !state.rng.chance(STEAL_CHANCE) {
+            loose.clear();
+        }
+        for item in loose {
+            world.move_object(&item, THIEF);
+            logging::info(format!(
+                "npc.steal id={} item={} from={}",
+                THIEF, item, here
+            ));
+        }
+    }
+
+    if carries_loot(world) && state.timers.remaining(THIEF_STASH).is_none() {
+        state.timers.schedule_fuse(THIEF_STASH, STASH_DELAY);
+    }
+
+    wander(state, world, i18n, &here);
+}
+
+/// Fuse: the thief slips back to his lair and drops his loot there.
+pub fn thief_stash(state: &mut GameState, world: &mut World) {
+    if !world.get_creature(THIEF).is_some_and(|c| c.is_alive()) {
+        return;
+    }
+
+    let loot: Vec<String> = loot_ids(world);
+    for item in &loot {
+        world.move_object(item, THIEF_LAIR);
+    }
+    let from = world.object_location(THIEF).unwrap_or("?").to_string();
+    world.move_creature(THIEF, THIEF_LAIR);
+    logging::info(format!(
+        "npc.stash id={} from={} to={} items={} player_room={}",
+        THIEF,
+        from,
+        THIEF_LAIR,
+        loot.join(","),
+        state.current_room
+    ));
+}
+
+fn wander(state: &mut GameState, world: &mut World, i18n: &I18n, here: &str) {
+    let Some(room) = world.rooms.get(here) else {
+        return;
+    };
+
+    let mut exits: Vec<(&String, &String)> = room
+        .exits
+        .iter()
+        .filter(|(_, to)| world.rooms.get(*to).is_some_and(|r| r.underground))
+        .filter(|(direction, _)| world.exit_barrier(here, direction).is_none())
+        .collect();
+    if exits.is_empty() {
+        return;
+    }
+    exits.sort();
+
+    let (direction, to) = exits[state.rng.below(exits.len())];
+    let (direction, to) = (direction.clone(), to.clone());
+    world.move_creature(THIEF, &to);
+    logging::info(format!(
+        "npc.move id={} from={} to={} dir={} player_room={}",
+        THIEF, here, to, direction, state.current_room
+    ));
+
+    if to == state.current_room
+        && let Some(thief) = world.get_creature(THIEF)
+    {
+        println!("\n{}", creature_display_line(thief, i18n));
+    }
+}
+
+/// The first valuable among `ids`, by id so the choice is stable.
+fn first_valuable<'a>(world: &World, ids: impl Iterator<Item = &'a String>) -> Option<String> {
+    let mut valuables: Vec<&String> = ids
+        .filter(|id| world.get_object(id).is_some_and(|obj| obj.valuable))
+        .collect();
+    valuables.sort();
+    valuables.first().map(|id| id.to_string())
+}
+
+fn loot_ids(world: &World) -> Vec<String> {
+    let mut loot: Vec<String> = world
+        .object_locations
+        .iter()
+        .filter(|(id, location)| {
+            *location == THIEF && world.get_object(id).is_some_and(|obj| obj.valuable)
+        })
+        .map(|(id, _)| id.clone())
+        .collect();
+    loot.sort();
+    loot
+}
+
+fn carries_loot(world: &World) -> bool {
+    !loot_ids(world).is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::actions::execute;
+    use crate::game::rng::Rng;
+    use crate::i18n::Language;
+    use crate::parser::{Command, Verb};
+
+    #[test]
+    fn thief_wanders_underground_steals_and_stashes() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+        let mut world = World::load_zork1();
+        let mut state = GameState::new(Language::ENGLISH, "round_room");
+        // A seed whose first roll robs the player.
+        state.rng = Rng::new(2);
+        start_daemons(&mut state, &world);
+
+        state.add_to_inventory("jade".to_string());
+        world.move_object("jade", "inventory");
+        state.add_to_inventory("sword".to_string());
+        world.move_object("sword", "inventory");
+
+        execute(&mut state, &mut world, Command::new(Verb::Look), &i18n);
+        assert!(!state.has_item("jade"));
+        assert!(state.has_item("sword"));
+        assert_eq!(world.object_location("jade"), Some(THIEF));
+        let room = world
+            .object_location(THIEF)
+            .expect("thief should be somewhere");
+        assert_ne!(room, "round_room");
+
+        for _ in 0..STASH_DELAY {
+            let room = world
+                .object_location(THIEF)
+                .expect("thief should be somewhere");
+            assert!(world.rooms[room].underground, "thief left the underground");
+            execute(&mut state, &mut world, Command::new(Verb::Look), &i18n);
+        }
+        assert_eq!(world.object_location("jade"), Some(THIEF_LAIR));
+        assert_eq!(world.object_location("stiletto"), Some(THIEF));
+    }
+
+    #[test]
+    fn thief_may_rob_the_player_he_is_with() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+        let mut world = World::load_zork1();
+        let mut state = GameState::new(Language::ENGLISH, "round_room");
+        state.add_to_inventory("jade".to_string());
+        world.move_object("jade", "inventory");
+
+        state.rng = Rng::new(2);
+        thief_daemon(&mut state, &mut world, &i18n);
+        assert!(!state.has_item("jade"));
+        assert_eq!(world.object_location("jade"), Some(THIEF));
+    }
+
+    #[test]
+    fn thief_may_leave_the_players_things_alone() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+        let mut world = World::load_zork1();
+        let mut state = GameState::new(Language::ENGLISH, "round_room");
+        state.add_to_inventory("jade".to_string());
+        world.move_object("jade", "inventory");
+
+        state.rng = Rng::new(1);
+        thief_daemon(&mut state, &mut world, &i18n);
+        assert!(state.has_item("jade"));
+        assert_eq!(world.object_location(THIEF), Some("round_room"));
+    }
+
+    #[test]
+    fn thief_lair_can_be_reached() {
+        let world = World::load_zork1();
+        assert!(world.reachable_rooms().contains(THIEF_LAIR));
+    }
+
+    #[test]
+    fn thief_only_takes_open_exits() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+        let mut world = World::load_zork1();
+        let mut state = GameState::new(Language::ENGLISH, "west_of_house");
+
+        for seed in 0..20 {
+            state.rng = Rng::new(seed);
+            world.move_creature(THIEF, "dome_room");
+            wander(&mut state, &mut world, &i18n, "dome_room");
+            assert_eq!(world.object_location(THIEF), Some("engravings_cave"));
+        }
+    }
+
+    #[test]
+    fn dead_thief_drops_what_he_carries() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+        let mut world = World::load_zork1();
+        let mut state = GameState::new(Language::ENGLISH, "round_room");
+        start_daemons(&mut state, &world);
+
+        state.rng = Rng::new(7);
+        world.move_object("jade", THIEF);
+        state.add_to_inventory("sword".to_string());
+        world.move_object("sword", "inventory");
+        for _ in 0..10 {
+            if !world.get_creature(THIEF).is_some_and(|c| c.is_alive()) {
+                break;
+            }
+            execute(
+                &mut state,
+                &mut world,
+                Command::new(Verb::Attack).with_direct("thief"),
+                &i18n,
+            );
+        }
+
+        assert!(!world.get_creature(THIEF).is_some_and(|c| c.is_alive()));
+        assert_eq!(world.object_location("jade"), Some("round_room"));
+        assert!(!state.timers.is_active(THIEF));
+    }
+}
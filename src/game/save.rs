@@ -0,0 +1,344 @@
+use crate::game::GameChoice;
+use crate::game::light::{self, LAMP_LIFE};
+use crate::game::npc;
+use crate::game::state::GameState;
+use crate::game::world::{ObjectSnapshot, World, WorldSnapshot};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Current on-disk save format. Version 1 is the legacy bare `GameState`
+/// written before saves carried world state.
+pub const SAVE_VERSION: u32 = 2;
+
+/// The lantern and the timer that burned it before light lived on objects.
+const LEGACY_LAMP: &str = "lamp";
+const LEGACY_LAMP_DAEMON: &str = "lamp";
+
+/// Slot used by a bare `save`/`restore`; matches the old `save_1.json`.
+pub const DEFAULT_SLOT: &str = "1";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveFile {
+    pub version: u32,
+    #[serde(default)]
+    pub saved_at: u64,
+    pub state: GameState,
+    #[serde(default)]
+    pub world: Option<WorldSnapshot>,
+}
+
+/// Why a save could not be written, read or deleted. Callers match on
+/// `NotFound` to tell a missing slot from a broken one.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SaveError {
+    NotFound,
+    Io(String),
+    Parse(String),
+    Serialize(String),
+}
+
+impl std::fmt::Display for SaveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SaveError::NotFound => f.write_str("No saved game found"),
+            SaveError::Io(message) | SaveError::Parse(message) | SaveError::Serialize(message) => {
+                f.write_str(message)
+            }
+        }
+    }
+}
+
+/// One entry of the `saves` listing.
+#[derive(Debug, Clone)]
+pub struct SaveSummary {
+    pub slot: String,
+    pub save: SaveFile,
+}
+
+impl SaveFile {
+    pub fn capture(state: &GameState, world: &World) -> Self {
+        SaveFile {
+            version: SAVE_VERSION,
+            saved_at: unix_ts(),
+            state: state.clone(),
+            world: Some(world.snapshot()),
+        }
+    }
+
+    pub fn write(&self, slot: &str) -> Result<(), SaveError> {
+        let save_path = GameState::save_path(self.state.game, slot);
+
+        let content = serde_json::to_string_pretty(self)
+            .map_err(|e| SaveError::Serialize(format!("Failed to serialize: {}", e)))?;
+
+        if let Some(parent) = save_path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|e| SaveError::Io(format!("Failed to create save directory: {}", e)))?;
+        }
+
+        fs::write(&save_path, content)
+            .map_err(|e| SaveError::Io(format!("Failed to write save: {}", e)))
+    }
+
+    pub fn read(game: Option<GameChoice>, slot: &str) -> Result<Self, SaveError> {
+        let save_path = locate(game, slot).ok_or(SaveError::NotFound)?;
+
+        let content = fs::read_to_string(&save_path)
+            .map_err(|e| SaveError::Io(format!("Failed to read save: {}", e)))?;
+
+        let mut save = Self::parse(&content)?;
+        if save.saved_at == 0 {
+            save.saved_at = fs::metadata(&save_path)
+                .and_then(|m| m.modified())
+                .ok()
+                .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+                .map(|d| d.as_secs())
+                .unwrap_or(0);
+        }
+
+        Ok(save)
+    }
+
+    pub fn parse(content: &str) -> Result<Self, SaveError> {
+        let parse_error =
+            |e: serde_json::Error| SaveError::Parse(format!("Failed to parse save: {}", e));
+        let value: serde_json::Value = serde_json::from_str(content).map_err(parse_error)?;
+
+        if value.get("version").is_none() {
+            let state: GameState = serde_json::from_value(value.clone()).map_err(parse_error)?;
+            let mut save = SaveFile {
+                version: 1,
+                saved_at: 0,
+                state,
+                world: None,
+            };
+            migrate_lamp(&value, &mut save);
+            return Ok(save);
+        }
+
+        let mut save: SaveFile = serde_json::from_value(value.clone()).map_err(parse_error)?;
+
+        if save.version > SAVE_VERSION {
+            return Err(SaveError::Parse(format!(
+                "Unsupported save version {}",
+                save.version
+            )));
+        }
+
+        if let Some(state) = value.get("state") {
+            migrate_lamp(state, &mut save);
+        }
+        Ok(save)
+    }
+
+    pub fn delete(game: Option<GameChoice>, slot: &str) -> Result<(), SaveError> {
+        let save_path = locate(game, slot).ok_or(SaveError::NotFound)?;
+
+        fs::remove_file(&save_path)
+            .map_err(|e| SaveError::Io(format!("Failed to delete save: {}", e)))
+    }
+
+    /// Every readable slot for `game`, including legacy un-namespaced ones,
+    /// sorted by slot name. Unparseable files are skipped rather than failing
+    /// the whole listing.
+    pub fn list(game: Option<GameChoice>) -> Vec<SaveSummary> {
+        let mut slots: Vec<String> = search_dirs(game)
+            .into_iter()
+            .filter_map(|dir| fs::read_dir(dir).ok())
+            .flat_map(|entries| entries.flatten())
+            .filter_map(|entry| {
+                let name = entry.file_name().into_string().ok()?;
+                let slot = name.strip_prefix("save_")?.strip_suffix(".json")?;
+                Some(slot.to_string())
+            })
+            .collect();
+
+        slots.sort();
+        slots.dedup();
+
+        slots
+            .into_iter()
+            .filter_map(|slot| {
+                let save = SaveFile::read(game, &slot).ok()?;
+                Some(SaveSummary { slot, save })
+            })
+            .collect()
+    }
+
+    /// Whether this save can be applied to the running world. Saves that
+    /// predate game tagging are accepted only if their room exists here.
+    pub fn fits(&self, game: Option<GameChoice>, world: &World) -> bool {
+        match (self.state.game, game) {
+            (Some(saved), Some(running)) if saved != running => false,
+            _ => world.rooms.contains_key(&self.state.current_room),
+        }
+    }
+
+    /// Replaces the live session with the saved one. Legacy saves have no
+    /// world section, so the world keeps its current state for them.
+    pub fn apply(self, state: &mut GameState, world: &mut World) {
+        if let Some(snapshot) = &self.world {
+            world.restore(snapshot);
+        }
+        *state = self.state;
+        npc::start_daemons(state, world);
+        light::start_daemon(state, world);
+    }
+}
+
+/// Saves written before light lived on objects kept the lantern in
+/// `lamp_lit` / `lamp_turns` and burned it with a "lamp" daemon. Moves that
+/// onto the lamp object; `apply` then restarts the light daemon.
+fn migrate_lamp(state: &Value, save: &mut SaveFile) {
+    save.state.timers.cancel(LEGACY_LAMP_DAEMON);
+
+    let lit = state
+        .get("lamp_lit")
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+    let turns = state
+        .get("lamp_turns")
+        .and_then(Value::as_u64)
+        .map_or(0, |turns| u32::try_from(turns).unwrap_or(u32::MAX));
+    if !lit && turns == 0 {
+        return;
+    }
+
+    let world = save.world.get_or_insert_with(WorldSnapshot::default);
+    let lamp = world
+        .objects
+        .entry(LEGACY_LAMP.to_string())
+        .or_insert_with(|| ObjectSnapshot {
+            is_open: false,
+            is_locked: false,
+            is_lit: false,
+            contents: Vec::new(),
+            tied_to: None,
+            fuel: None,
+        });
+    lamp.fuel = Some(LAMP_LIFE.saturating_sub(turns));
+    lamp.is_lit = lit && turns < LAMP_LIFE;
+}
+
+fn search_dirs(game: Option<GameChoice>) -> Vec<PathBuf> {
+    match game {
+        Some(_) => vec![GameState::save_dir(game), GameState::save_dir(None)],
+        None => vec![GameState::save_dir(None)],
+    }
+}
+
+/// The per-game file wins over a legacy root-level slot of the same name.
+fn locate(game: Option<GameChoice>, slot: &str) -> Option<PathBuf> {
+    search_dirs(game)
+        .into_iter()
+        .map(|dir| dir.join(format!("save_{}.json", slot)))
+        .find(|path| path.exists())
+}
+
+/// Normalizes a player-typed slot name into a safe file-name fragment.
+pub fn slot_name(input: Option<&str>) -> Option<String> {
+    let Some(input) = input.map(str::trim).filter(|s| !s.is_empty()) else {
+        return Some(DEFAULT_SLOT.to_string());
+    };
+
+    let slot = input
+        .to_lowercase()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join("_");
+
+    if slot.len() > 32
+        || !slot
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '-')
+    {
+        return None;
+    }
+
+    Some(slot)
+}
+
+/// Formats a unix timestamp as `YYYY-MM-DD HH:MM` (UTC).
+pub fn format_timestamp(secs: u64) -> String {
+    if secs == 0 {
+        return "-".to_string();
+    }
+
+    let days = (secs / 86_400) as i64;
+    let rem = secs % 86_400;
+
+    // Civil-from-days (Howard Hinnant), valid for the whole unix era.
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}",
+        year,
+        month,
+        day,
+        rem / 3_600,
+        (rem % 3_600) / 60
+    )
+}
+
+fn unix_ts() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::Language;
+
+    #[test]
+    fn slot_names_are_sanitized() {
+        assert_eq!(slot_name(None).as_deref(), Some("1"));
+        assert_eq!(slot_name(Some("  ")).as_deref(), Some("1"));
+        assert_eq!(
+            slot_name(Some("Before Troll")).as_deref(),
+            Some("before_troll")
+        );
+        assert_eq!(slot_name(Some("../etc/passwd")), None);
+    }
+
+    #[test]
+    fn broken_saves_are_parse_errors() {
+        assert!(matches!(
+            SaveFile::parse("{ not json"),
+            Err(SaveError::Parse(_))
+        ));
+        let mut future = serde_json::to_value(SaveFile::capture(
+            &GameState::new(Language::ENGLISH, "west_of_house"),
+            &World::load_zork1(),
+        ))
+        .expect("save should serialize");
+        future["version"] = Value::from(SAVE_VERSION + 1);
+        assert_eq!(
+            SaveFile::parse(&future.to_string()).map(|save| save.version),
+            Err(SaveError::Parse(format!(
+                "Unsupported save version {}",
+                SAVE_VERSION + 1
+            )))
+        );
+    }
+
+    #[test]
+    fn timestamps_format_as_utc() {
+        assert_eq!(format_timestamp(0), "-");
+        assert_eq!(format_timestamp(951_782_400), "2000-02-29 00:00");
+        assert_eq!(format_timestamp(1_790_000_000), "2026-09-21 14:13");
+    }
+}
@@ -0,0 +1,156 @@
+use crate::game::state::GameState;
+use crate::game::world::World;
+use crate::logging;
+
+/// The Zork I container that turns treasures into points.
+pub const TROPHY_CASE: &str = "trophy_case";
+
+/// Awards a treasure's first-touch points, once per game.
+pub fn touch(state: &mut GameState, world: &World, object_id: &str) {
+    let points = world
+        .get_object(object_id)
+        .map_or(0, |obj| obj.touch_points);
+    award(state, points, format!("touch:{}", object_id));
+}
+
+/// Awards the points for a first visit to `room_id`.
+pub fn enter_room(state: &mut GameState, world: &World, room_id: &str) {
+    let points = world.rooms.get(room_id).map_or(0, |room| room.points);
+    award(state, points, format!("room:{}", room_id));
+}
+
+/// A treasure went into `container_id`; the trophy case pays its deposit.
+pub fn deposit(state: &mut GameState, world: &World, object_id: &str, container_id: &str) {
+    if container_id != TROPHY_CASE {
+        return;
+    }
+    let points = world.get_object(object_id).map_or(0, |obj| obj.case_points);
+    award(state, points, format!("case:{}", object_id));
+}
+
+/// A treasure left `container_id`; taking it out of the case gives the
+/// deposit back.
+pub fn withdraw(state: &mut GameState, world: &World, object_id: &str, container_id: &str) {
+    let key = format!("case:{}", object_id);
+    if container_id != TROPHY_CASE || !state.scored.contains(&key) {
+        return;
+    }
+    let points = world.get_object(object_id).map_or(0, |obj| obj.case_points);
+    state.scored.retain(|scored| *scored != key);
+    state.score = state.score.saturating_sub(points);
+    logging::info(format!(
+        "score.lost key={} points={} score={}",
+        key, points, state.score
+    ));
+}
+
+/// Everything a player can earn in this world as loaded: every obtainable
+/// treasure touched, and cased if the world has a trophy case to reach,
+/// and every reachable scoring room visited. Points stranded in unported
+/// areas are left out, so ranks stay attainable. The loader stores it as
+/// `World::max_score`, which later moves cannot change.
+pub fn earnable(world: &World) -> u32 {
+    let reached = world.reachable_rooms();
+    let has_case = world.is_obtainable(&reached, TROPHY_CASE);
+    let objects: u32 = world
+        .objects
+        .values()
+        .filter(|obj| world.is_obtainable(&reached, &obj.id))
+        .map(|obj| obj.touch_points + if has_case { obj.case_points } else { 0 })
+        .sum();
+    let rooms: u32 = world
+        .rooms
+        .values()
+        .filter(|room| reached.contains(room.id.as_str()))
+        .map(|room| room.points)
+        .sum();
+    objects + rooms
+}
+
+fn award(state: &mut GameState, points: u32, key: String) {
+    if points == 0 || state.scored.contains(&key) {
+        return;
+    }
+    state.add_score(points);
+    logging::info(format!(
+        "score.award key={} points={} score={}",
+        key, points, state.score
+    ));
+    state.scored.push(key);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::actions::execute;
+    use crate::game::loader::parse_world;
+    use crate::i18n::{I18n, Language};
+    use crate::parser::{Command, Preposition, Verb};
+
+    #[test]
+    fn treasures_score_when_touched_and_while_cased() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+        let mut world = World::load_zork1();
+        let mut state = GameState::new(Language::ENGLISH, "living_room");
+        world.move_object("skull", "living_room");
+        let mut run = |cmd: Command| execute(&mut state, &mut world, cmd, &i18n);
+
+        run(Command::new(Verb::Take).with_direct("skull"));
+        run(Command::new(Verb::Drop).with_direct("skull"));
+        run(Command::new(Verb::Take).with_direct("skull"));
+        run(Command::new(Verb::Open).with_direct("case"));
+        let put = Command::new(Verb::Put)
+            .with_direct("skull")
+            .with_indirect(Preposition::In, "case");
+        run(put.clone());
+        run(Command::new(Verb::Take).with_direct("skull"));
+        run(put);
+        run(Command::new(Verb::East));
+
+        assert_eq!(state.score, 10 + 10 + 10);
+        assert_eq!(world.object_location("skull"), Some(TROPHY_CASE));
+        assert!(state.scored.contains(&"room:kitchen".to_string()));
+    }
+
+    #[test]
+    fn maximum_counts_only_what_can_be_earned_and_stays_fixed() {
+        // Zork I defines 300 of its 350 points, all but the emerald's in
+        // ported areas; Zork II has no trophy case and 25 points in
+        // unported areas.
+        assert_eq!(World::load_zork1().max_score, 285);
+        assert_eq!(World::load_zork2().max_score, 70);
+        assert_eq!(World::load_zork3().max_score, 7);
+
+        let mut world = parse_world(
+            r#"{
+                "start": "hall",
+                "unported": [
+                    {"id": "attic", "reason": "no stairs", "note": "docs/PORTING.md#attic"},
+                    {"id": "vault", "reason": "not extracted", "note": "docs/PORTING.md#vault"}
+                ],
+                "rooms": [
+                    {"id": "hall", "name": "Hall", "points": 1},
+                    {"id": "attic", "name": "Attic", "points": 2}
+                ],
+                "objects": [
+                    {"id": "trophy_case", "name": "case", "location": "hall",
+                     "contents": ["ring"], "takeable": false},
+                    {"id": "ring", "name": "ring", "location": "trophy_case",
+                     "treasure": {"touch": 4, "case": 8}},
+                    {"id": "coin", "name": "coin", "location": "attic",
+                     "treasure": {"touch": 16, "case": 32}},
+                    {"id": "gem", "name": "gem", "location": "vault",
+                     "treasure": {"touch": 64}}
+                ]
+            }"#,
+        )
+        .expect("world should parse");
+        assert_eq!(world.max_score, 1 + 4 + 8);
+
+        // Carrying the coin out of the attic makes it earnable, but the
+        // maximum stays what it was when the game began.
+        world.move_object("coin", "hall");
+        assert_eq!(earnable(&world), 1 + 4 + 8 + 16 + 32);
+        assert_eq!(world.max_score, 1 + 4 + 8);
+    }
+}
@@ -1,20 +1,51 @@
+use crate::game::GameChoice;
+use crate::game::rng::Rng;
+use crate::game::timers::Scheduler;
 use crate::i18n::Language;
 use serde::{Deserialize, Serialize};
-use std::fs;
 use std::path::PathBuf;
 
+/// Health of an unhurt player.
+pub const MAX_HEALTH: i32 = 6;
+
+fn full_health() -> i32 {
+    MAX_HEALTH
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameState {
     pub current_room: String,
     pub inventory: Vec<String>,
     pub language: String,
     pub score: u32,
+    /// Awards already paid out (`touch:<id>`, `case:<id>`, `room:<id>`), so
+    /// nothing scores twice.
+    #[serde(default)]
+    pub scored: Vec<String>,
     pub moves: u32,
     pub visited_rooms: Vec<String>,
-    pub lamp_lit: bool,
-    pub lamp_turns: u32,
+    /// Wounds the player can still take; reaching zero is a death.
+    #[serde(default = "full_health")]
+    pub health: i32,
+    /// Deaths so far. The first ones are forgiven with a resurrection.
+    #[serde(default)]
+    pub deaths: u32,
     pub player_dead: bool,
+    /// Turns in a row spent in the dark; each one after the first risks
+    /// the grue.
+    #[serde(default)]
+    pub dark_turns: u32,
+    /// The game's victory condition has been met.
+    #[serde(default)]
+    pub won: bool,
     pub object_states: std::collections::HashMap<String, ObjectState>,
+    #[serde(default)]
+    pub game: Option<GameChoice>,
+    #[serde(default)]
+    pub timers: Scheduler,
+    /// Saved so a restored game rolls the same dice it would have.
+    #[serde(default)]
+    pub rng: Rng,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -31,17 +62,23 @@ impl GameState {
             inventory: Vec::new(),
             language: lang.code().to_string(),
             score: 0,
+            scored: Vec::new(),
             moves: 0,
             visited_rooms: vec![start_room.to_string()],
-            lamp_lit: false,
-            lamp_turns: 0,
+            health: MAX_HEALTH,
+            deaths: 0,
             player_dead: false,
+            dark_turns: 0,
+            won: false,
             object_states: std::collections::HashMap::new(),
+            game: None,
+            timers: Scheduler::default(),
+            rng: Rng::default(),
         }
     }
 
     pub fn language(&self) -> Language {
-        Language::from_code(&self.language).unwrap_or(Language::English)
+        Language::from_code(&self.language).unwrap_or(Language::ENGLISH)
     }
 
     pub fn move_to(&mut self, room_id: &str) {
@@ -75,43 +112,22 @@ impl GameState {
         self.score += points;
     }
 
-    pub fn save(&self, slot: u32) -> Result<(), String> {
-        let save_path = Self::save_path(slot);
-
-        let content = serde_json::to_string_pretty(self)
-            .map_err(|e| format!("Failed to serialize: {}", e))?;
-
-        if let Some(parent) = save_path.parent() {
-            fs::create_dir_all(parent)
-                .map_err(|e| format!("Failed to create save directory: {}", e))?;
-        }
-
-        fs::write(&save_path, content).map_err(|e| format!("Failed to write save: {}", e))?;
-
-        Ok(())
-    }
-
-    pub fn load(slot: u32) -> Result<Self, String> {
-        let save_path = Self::save_path(slot);
-
-        if !save_path.exists() {
-            return Err("No saved game found".to_string());
+    /// Saves live in `~/.zork-termux/<game>/`; the bare directory only holds
+    /// slots written before saves were namespaced per game.
+    pub fn save_dir(game: Option<GameChoice>) -> PathBuf {
+        let home = std::env::var("HOME").unwrap_or_else(|_| ".".to_string());
+        let root = PathBuf::from(home).join(".zork-termux");
+        match game {
+            Some(game) => root.join(game.code()),
+            None => root,
         }
-
-        let content =
-            fs::read_to_string(&save_path).map_err(|e| format!("Failed to read save: {}", e))?;
-
-        serde_json::from_str(&content).map_err(|e| format!("Failed to parse save: {}", e))
     }
 
-    pub fn save_path(slot: u32) -> PathBuf {
-        let home = std::env::var("HOME").unwrap_or_else(|_| ".".to_string());
-        PathBuf::from(home)
-            .join(".zork-termux")
-            .join(format!("save_{}.json", slot))
+    pub fn save_path(game: Option<GameChoice>, slot: &str) -> PathBuf {
+        Self::save_dir(game).join(format!("save_{}.json", slot))
     }
 
-    pub fn save_exists(slot: u32) -> bool {
-        Self::save_path(slot).exists()
+    pub fn save_exists(game: Option<GameChoice>, slot: &str) -> bool {
+        Self::save_path(game, slot).exists()
     }
 }
@@ -0,0 +1,116 @@
+use serde::{Deserialize, Serialize};
+
+/// Fuses count down and fire once; daemons fire every turn until stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TimerKind {
+    Fuse,
+    Daemon,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timer {
+    pub id: String,
+    pub kind: TimerKind,
+    /// Turns left before a fuse fires; unused for daemons.
+    pub turns: u32,
+}
+
+/// Turn-based event queue, stored in `GameState` so saves and undo carry it.
+/// `actions::execute` ticks it once per turn-consuming command and dispatches
+/// whatever fired by id.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Scheduler {
+    timers: Vec<Timer>,
+}
+
+impl Scheduler {
+    /// Arms (or re-arms) a fuse that fires after `turns` ticks.
+    pub fn schedule_fuse(&mut self, id: &str, turns: u32) {
+        self.cancel(id);
+        self.timers.push(Timer {
+            id: id.to_string(),
+            kind: TimerKind::Fuse,
+            turns,
+        });
+    }
+
+    pub fn start_daemon(&mut self, id: &str) {
+        if !self.is_active(id) {
+            self.timers.push(Timer {
+                id: id.to_string(),
+                kind: TimerKind::Daemon,
+                turns: 0,
+            });
+        }
+    }
+
+    pub fn cancel(&mut self, id: &str) {
+        self.timers.retain(|timer| timer.id != id);
+    }
+
+    pub fn is_active(&self, id: &str) -> bool {
+        self.timers.iter().any(|timer| timer.id == id)
+    }
+
+    /// Turns left on a fuse, if one with this id is armed.
+    pub fn remaining(&self, id: &str) -> Option<u32> {
+        self.timers
+            .iter()
+            .find(|timer| timer.id == id && timer.kind == TimerKind::Fuse)
+            .map(|timer| timer.turns)
+    }
+
+    /// Advances one turn and returns the ids that fire, in scheduling order.
+    /// Fired fuses are disarmed; daemons stay.
+    pub fn tick(&mut self) -> Vec<String> {
+        let mut fired = Vec::new();
+
+        for timer in &mut self.timers {
+            match timer.kind {
+                TimerKind::Daemon => fired.push(timer.id.clone()),
+                TimerKind::Fuse => {
+                    timer.turns = timer.turns.saturating_sub(1);
+                    if timer.turns == 0 {
+                        fired.push(timer.id.clone());
+                    }
+                }
+            }
+        }
+
+        self.timers
+            .retain(|timer| timer.kind == TimerKind::Daemon || timer.turns > 0);
+        fired
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuses_fire_once_and_daemons_every_turn() {
+        let mut scheduler = Scheduler::default();
+        scheduler.start_daemon("lamp");
+        scheduler.schedule_fuse("bell", 2);
+
+        assert_eq!(scheduler.tick(), vec!["lamp"]);
+        assert_eq!(scheduler.remaining("bell"), Some(1));
+        assert_eq!(scheduler.tick(), vec!["lamp", "bell"]);
+        assert!(!scheduler.is_active("bell"));
+        assert_eq!(scheduler.tick(), vec!["lamp"]);
+
+        scheduler.cancel("lamp");
+        assert!(scheduler.tick().is_empty());
+    }
+
+    #[test]
+    fn scheduler_round_trips_through_json() {
+        let mut scheduler = Scheduler::default();
+        scheduler.schedule_fuse("bell", 5);
+        scheduler.start_daemon("lamp");
+
+        let json = serde_json::to_string(&scheduler).expect("scheduler should serialize");
+        let restored: Scheduler = serde_json::from_str(&json).expect("scheduler should parse");
+        assert_eq!(restored, scheduler);
+    }
+}
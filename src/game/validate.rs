@@ -0,0 +1,249 @@
+use crate::game::world::{NOWHERE, World};
+use std::collections::HashSet;
+
+/// Locations that are neither rooms, containers nor creatures.
+const SENTINELS: [&str; 2] = ["inventory", NOWHERE];
+
+/// Checks that every id the world refers to exists and that every room can
+/// be reached from the start. Missing and unreachable ids the world lists
+/// as unported are let through, but a listed id that excuses nothing is a
+/// problem, so the list cannot go stale. Returns one line per problem,
+/// sorted so runs compare cleanly; an empty list means the world is sound.
+pub fn check(world: &World) -> Vec<String> {
+    let mut problems = Vec::new();
+    let mut excused = HashSet::new();
+    let is_room = |id: &str| world.rooms.contains_key(id);
+    let is_object = |id: &str| world.objects.contains_key(id);
+
+    let rooms = [
+        ("start", Some(&world.start_room)),
+        ("resurrection", world.resurrection_room.as_ref()),
+    ];
+    for (name, room) in rooms
+        .into_iter()
+        .filter_map(|(name, room)| Some((name, room?)))
+    {
+        if !is_room(room) {
+            problems.push(format!("{} room `{}` does not exist", name, room));
+        }
+    }
+
+    for room in world.rooms.values() {
+        for (direction, destination) in &room.exits {
+            if !is_room(destination) {
+                problems.push(format!(
+                    "room `{}`: exit `{}` leads to unknown room `{}`",
+                    room.id, direction, destination
+                ));
+            }
+        }
+        for (direction, blocker) in &room.blocked_exits {
+            if !is_object(blocker) {
+                problems.push(format!(
+                    "room `{}`: exit `{}` is blocked by unknown object `{}`",
+                    room.id, direction, blocker
+                ));
+            }
+        }
+    }
+
+    for obj in world.objects.values() {
+        if let Some(destination) = &obj.enter_destination
+            && !is_room(destination)
+        {
+            problems.push(format!(
+                "object `{}`: enters unknown room `{}`",
+                obj.id, destination
+            ));
+        }
+        if let Some(key) = &obj.key
+            && !is_object(key)
+        {
+            problems.push(format!("object `{}`: unknown key `{}`", obj.id, key));
+        }
+        for content in obj.contents.iter().filter(|id| !is_object(id)) {
+            if world.unported.contains_key(content) {
+                excused.insert(content.as_str());
+            } else {
+                problems.push(format!(
+                    "object `{}`: contains unknown object `{}`",
+                    obj.id, content
+                ));
+            }
+        }
+    }
+
+    for (id, location) in &world.object_locations {
+        let known = is_room(location)
+            || is_object(location)
+            || world.creatures.contains_key(location)
+            || SENTINELS.contains(&location.as_str());
+        if !known && world.unported.contains_key(location) {
+            excused.insert(location.as_str());
+        } else if !known {
+            problems.push(format!("`{}`: unknown location `{}`", id, location));
+        }
+    }
+
+    for creature in world.creatures.values() {
+        for (from, to) in &creature.blocks_exits {
+            let leads_there = world
+                .rooms
+                .get(from)
+                .is_some_and(|room| room.exits.values().any(|dest| dest == to));
+            if !leads_there {
+                problems.push(format!(
+                    "creature `{}`: blocks `{}` -> `{}`, which is not an exit",
+                    creature.id, from, to
+                ));
+            }
+        }
+    }
+
+    if is_room(&world.start_room) {
+        let reached = world.reachable_rooms();
+        for id in world
+            .rooms
+            .keys()
+            .filter(|id| !reached.contains(id.as_str()))
+        {
+            if world.unported.contains_key(id) {
+                excused.insert(id.as_str());
+            } else {
+                problems.push(format!(
+                    "room `{}`: unreachable from `{}`",
+                    id, world.start_room
+                ));
+            }
+        }
+    }
+
+    for id in world
+        .unported
+        .keys()
+        .filter(|id| !excused.contains(id.as_str()))
+    {
+        problems.push(format!(
+            "unported `{}`: neither missing nor unreachable",
+            id
+        ));
+    }
+
+    problems.sort();
+    problems
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::GameChoice;
+    use crate::game::loader::parse_world;
+
+    #[test]
+    fn embedded_worlds_are_sound() {
+        // Unported areas are listed in each world file; anything else is a
+        // bug in the port.
+        for choice in GameChoice::ALL {
+            let problems = check(&World::load(choice));
+            assert!(problems.is_empty(), "{}: {:#?}", choice.code(), problems);
+        }
+    }
+
+    #[test]
+    fn dangling_ids_and_unreachable_rooms_are_reported() {
+        let world = parse_world(
+            r#"{
+                "start": "hall",
+                "rooms": [
+                    {"id": "hall", "name": "Hall", "exits": {"north": "attic"},
+                     "blocked_exits": {"north": "hatch"}},
+                    {"id": "cellar", "name": "Cellar"}
+                ],
+                "objects": [
+                    {"id": "box", "name": "box", "location": "hall",
+                     "contents": ["gem"], "enter": "vault"},
+                    {"id": "coin", "name": "coin", "location": "inventory"},
+                    {"id": "ash", "name": "ash", "location": "nowhere"}
+                ],
+                "creatures": [
+                    {"id": "troll", "name": "troll", "location": "hall", "hp": 2,
+                     "blocks": [["hall", "cellar"]]}
+                ]
+            }"#,
+        )
+        .expect("world should parse");
+
+        assert_eq!(
+            check(&world),
+            [
+                "creature `troll`: blocks `hall` -> `cellar`, which is not an exit",
+                "object `box`: contains unknown object `gem`",
+                "object `box`: enters unknown room `vault`",
+                "room `cellar`: unreachable from `hall`",
+                "room `hall`: exit `north` is blocked by unknown object `hatch`",
+                "room `hall`: exit `north` leads to unknown room `attic`",
+            ]
+        );
+    }
+
+    #[test]
+    fn unported_ids_are_excused_until_they_go_stale() {
+        let world = parse_world(
+            r#"{
+                "start": "hall",
+                "unported": [
+                    {"id": "cellar", "reason": "no stairs yet", "note": "docs/PORTING.md#cellar"},
+                    {"id": "gem", "reason": "not extracted", "note": "docs/PORTING.md#gem"},
+                    {"id": "tower", "reason": "not extracted", "note": "docs/PORTING.md#tower"},
+                    {"id": "hall", "reason": "stale", "note": "docs/PORTING.md#hall"}
+                ],
+                "rooms": [
+                    {"id": "hall", "name": "Hall"},
+                    {"id": "cellar", "name": "Cellar"}
+                ],
+                "objects": [
+                    {"id": "box", "name": "box", "location": "hall", "contents": ["gem"]},
+                    {"id": "flag", "name": "flag", "location": "tower"}
+                ]
+            }"#,
+        )
+        .expect("world should parse");
+
+        assert_eq!(
+            check(&world),
+            ["unported `hall`: neither missing nor unreachable"]
+        );
+    }
+
+    #[test]
+    fn unported_entries_point_at_porting_notes() {
+        let sections: Vec<String> = include_str!("../../docs/PORTING.md")
+            .lines()
+            .filter_map(|line| line.strip_prefix("## "))
+            .map(anchor)
+            .collect();
+        for choice in GameChoice::ALL {
+            for (id, unported) in &World::load(choice).unported {
+                let section = unported.note.strip_prefix("docs/PORTING.md#");
+                assert!(
+                    section.is_some_and(|section| sections.iter().any(|s| s == section)),
+                    "{}: `{}` points at {}",
+                    choice.code(),
+                    id,
+                    unported.note
+                );
+            }
+        }
+    }
+
+    /// The anchor GitHub gives a Markdown heading.
+    fn anchor(heading: &str) -> String {
+        heading
+            .trim()
+            .to_lowercase()
+            .chars()
+            .filter(|c| c.is_alphanumeric() || *c == ' ' || *c == '-')
+            .map(|c| if c == ' ' { '-' } else { c })
+            .collect()
+    }
+}
@@ -0,0 +1,206 @@
+use super::{I18n, Language, TranslationData, loader};
+use crate::game::world::World;
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// How English descriptions open; a translated description starting like
+/// this was most likely copied over untranslated.
+const ENGLISH_OPENERS: [&str; 4] = ["there is ", "there are ", "you see ", "you can see "];
+
+/// Translation sections keyed by world ids, in report order.
+const SECTIONS: [&str; 3] = ["rooms", "objects", "creatures"];
+
+/// What a translation pack lacks or carries needlessly for a set of worlds.
+/// Keys are `<section>.<id>` (`rooms.cellar`), with `.name` or
+/// `.description` appended for untranslated fields, grouped by section and
+/// sorted by id.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct Coverage {
+    pub language: String,
+    /// World ids with no translation; the game shows their English name.
+    pub missing: Vec<String>,
+    /// Translated fields that still read as English.
+    pub untranslated: Vec<String>,
+    /// Translations for ids that no world defines.
+    pub orphaned: Vec<String>,
+}
+
+impl Coverage {
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty() && self.untranslated.is_empty()
+    }
+}
+
+/// Whether a description in `lang` looks like English text left in place.
+pub fn reads_as_english(lang: &Language, description: &str) -> bool {
+    if *lang == Language::ENGLISH {
+        return false;
+    }
+
+    let lowered = description.trim_start().to_lowercase();
+    ENGLISH_OPENERS
+        .iter()
+        .any(|opener| lowered.starts_with(opener))
+}
+
+struct Entry<'a> {
+    name: &'a str,
+    description: &'a str,
+}
+
+impl I18n {
+    /// Cross-references every room, object and creature of `worlds` with
+    /// this pack. Names and descriptions are compared with the world data
+    /// and the embedded English pack to spot untranslated text.
+    pub fn coverage(&self, worlds: &[World]) -> Coverage {
+        let english = (self.language != Language::ENGLISH)
+            .then(|| loader::load_translation(&Language::ENGLISH).ok())
+            .flatten();
+        let reference = english.as_ref().map(entries);
+
+        let mut coverage = Coverage {
+            language: self.language.code().to_string(),
+            ..Coverage::default()
+        };
+        let sections = SECTIONS
+            .into_iter()
+            .zip(world_names(worlds))
+            .zip(entries(&self.data))
+            .enumerate();
+        for (index, ((section, names), translated)) in sections {
+            let reference = reference.as_ref().map(|all| &all[index]);
+
+            for id in names.keys() {
+                if !translated.contains_key(id) {
+                    coverage.missing.push(format!("{}.{}", section, id));
+                }
+            }
+
+            for (id, entry) in &translated {
+                let key = format!("{}.{}", section, id);
+                if !names.contains_key(id) {
+                    coverage.orphaned.push(key);
+                    continue;
+                }
+                if self.language == Language::ENGLISH {
+                    continue;
+                }
+
+                let english = reference.and_then(|entries| entries.get(id));
+                let english_name = english.map(|e| e.name);
+                if [names.get(id).copied(), english_name]
+                    .into_iter()
+                    .flatten()
+                    .any(|name| name.eq_ignore_ascii_case(entry.name))
+                {
+                    coverage.untranslated.push(format!("{}.name", key));
+                }
+                let copied = english.is_some_and(|e| {
+                    !e.description.is_empty() && e.description == entry.description
+                });
+                if copied || reads_as_english(&self.language, entry.description) {
+                    coverage.untranslated.push(format!("{}.description", key));
+                }
+            }
+        }
+
+        coverage
+    }
+}
+
+/// The English name every world gives each id, per section.
+fn world_names(worlds: &[World]) -> [BTreeMap<&str, &str>; 3] {
+    let mut names: [BTreeMap<&str, &str>; 3] = Default::default();
+    for world in worlds {
+        names[0].extend(
+            world
+                .rooms
+                .values()
+                .map(|r| (r.id.as_str(), r.name.as_str())),
+        );
+        names[1].extend(
+            world
+                .objects
+                .values()
+                .map(|o| (o.id.as_str(), o.name.as_str())),
+        );
+        names[2].extend(
+            world
+                .creatures
+                .values()
+                .map(|c| (c.id.as_str(), c.name.as_str())),
+        );
+    }
+    names
+}
+
+/// A pack's name and description for each id, per section.
+fn entries(data: &TranslationData) -> [BTreeMap<&str, Entry<'_>>; 3] {
+    [
+        data.rooms
+            .iter()
+            .map(|(id, t)| (id.as_str(), Entry::new(&t.name, &t.description)))
+            .collect(),
+        data.objects
+            .iter()
+            .map(|(id, t)| (id.as_str(), Entry::new(&t.name, &t.description)))
+            .collect(),
+        data.creatures
+            .iter()
+            .map(|(id, t)| (id.as_str(), Entry::new(&t.name, &t.description)))
+            .collect(),
+    ]
+}
+
+impl<'a> Entry<'a> {
+    fn new(name: &'a str, description: &'a str) -> Self {
+        Entry { name, description }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::GameChoice;
+    use crate::i18n::RoomTranslation;
+
+    #[test]
+    fn embedded_packs_cover_every_world_id() {
+        let worlds: Vec<World> = GameChoice::ALL.into_iter().map(World::load).collect();
+        for lang in [Language::ITALIAN, Language::SPANISH] {
+            let coverage = I18n::load(lang.clone())
+                .expect("pack should load")
+                .coverage(&worlds);
+            assert_eq!(coverage.language, lang.code());
+            assert!(coverage.missing.is_empty(), "{:?}", coverage.missing);
+        }
+    }
+
+    #[test]
+    fn gaps_copies_and_leftovers_are_reported() {
+        let mut i18n = I18n::load(Language::ITALIAN).expect("pack should load");
+        let rooms = &mut i18n.data.rooms;
+        rooms.remove("cellar");
+        rooms.insert(
+            "belfry".to_string(),
+            RoomTranslation {
+                name: "Cella campanaria".to_string(),
+                description: "Una campana arrugginita.".to_string(),
+            },
+        );
+        let kitchen = rooms.get_mut("kitchen").expect("kitchen");
+        kitchen.name = "Kitchen".to_string();
+        kitchen.description = "There is a table here.".to_string();
+
+        let coverage = i18n.coverage(&[World::load_zork1()]);
+        assert_eq!(coverage.missing, ["rooms.cellar"]);
+        assert!(coverage.orphaned.contains(&"rooms.belfry".to_string()));
+        for key in ["rooms.kitchen.name", "rooms.kitchen.description"] {
+            assert!(coverage.untranslated.contains(&key.to_string()), "{key}");
+        }
+        assert!(!coverage.is_complete());
+
+        let json = serde_json::to_value(&coverage).expect("coverage serializes");
+        assert_eq!(json["missing"][0], "rooms.cellar");
+    }
+}
@@ -1,26 +1,139 @@
 use super::{Language, TranslationData};
+use serde_json::Value;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-const EMBEDDED_EN: &str = include_str!("../../data/i18n/en.json");
-const EMBEDDED_IT: &str = include_str!("../../data/i18n/it.json");
-const EMBEDDED_ES: &str = include_str!("../../data/i18n/es.json");
+/// Packs built into the binary, by language code. English comes first: it
+/// is the base for languages that have no embedded pack.
+const EMBEDDED: [(&str, &str); 3] = [
+    ("en", include_str!("../../data/i18n/en.json")),
+    ("it", include_str!("../../data/i18n/it.json")),
+    ("es", include_str!("../../data/i18n/es.json")),
+];
 
-pub fn load_translation(lang: Language) -> Result<TranslationData, String> {
-    let json_str = match lang {
-        Language::English => EMBEDDED_EN,
-        Language::Italian => EMBEDDED_IT,
-        Language::Spanish => EMBEDDED_ES,
+fn embedded(lang: &Language) -> Option<&'static str> {
+    EMBEDDED
+        .iter()
+        .find(|(code, _)| *code == lang.code())
+        .map(|(_, json)| *json)
+}
+
+pub fn load_translation(lang: &Language) -> Result<TranslationData, String> {
+    let json = embedded(lang).ok_or_else(|| format!("No translation pack for `{}`", lang))?;
+    serde_json::from_str(json).map_err(|e| format!("Failed to parse translation: {}", e))
+}
+
+/// The embedded pack for `lang` with the pack at `path` merged on top.
+pub fn load_translation_from_file(lang: &Language, path: &Path) -> Result<TranslationData, String> {
+    load_layered(lang, &[path.to_path_buf()])
+}
+
+/// The embedded pack for `lang` with each of `packs` merged on top in
+/// order. Packs may be partial: objects merge key by key, so a pack holding
+/// only `ui.welcome` and `rooms.cellar.name` overrides just those, while
+/// keys the embedded pack lacks (a new room, a whole new language's text)
+/// are added. A language with no embedded pack starts from English, so its
+/// packs only need the text they translate.
+pub fn load_layered(lang: &Language, packs: &[PathBuf]) -> Result<TranslationData, String> {
+    let mut merged: Value = match embedded(lang) {
+        Some(json) => {
+            serde_json::from_str(json).map_err(|e| format!("Failed to parse translation: {}", e))?
+        }
+        None if packs.is_empty() => return Err(format!("No translation pack for `{}`", lang)),
+        None => {
+            let mut english: Value = serde_json::from_str(EMBEDDED[0].1)
+                .map_err(|e| format!("Failed to parse translation: {}", e))?;
+            english["meta"]["language"] = Value::from(lang.code());
+            english["meta"]["name"] = Value::from(lang.code());
+            english
+        }
+    };
+    let mut data = serde_json::from_value(merged.clone())
+        .map_err(|e| format!("Failed to parse translation: {}", e))?;
+
+    for path in packs {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read translation file {}: {}", path.display(), e))?;
+        let pack: Value = serde_json::from_str(&content)
+            .map_err(|e| format!("Failed to parse translation {}: {}", path.display(), e))?;
+        if !pack.is_object() {
+            return Err(format!(
+                "Failed to parse translation {}: expected a JSON object",
+                path.display()
+            ));
+        }
+        merge(&mut merged, pack);
+        // Checked per pack so a wrong type is blamed on the file that has it.
+        data = serde_json::from_value(merged.clone())
+            .map_err(|e| format!("Failed to parse translation {}: {}", path.display(), e))?;
+    }
+
+    Ok(data)
+}
+
+/// The language among `packs` (as from `available_packs`) that `input`
+/// names: the name a pack gives its language (`Italiano`), in any case, or
+/// a code or locale (`it`, `it_IT`).
+pub fn language_named(packs: &[(Language, String)], input: &str) -> Option<Language> {
+    let input = input.trim();
+    let by_name = packs
+        .iter()
+        .find(|(_, name)| name.to_lowercase() == input.to_lowercase());
+    let by_code = || {
+        let code = Language::from_code(input)?;
+        packs.iter().find(|(language, _)| *language == code)
     };
+    by_name
+        .or_else(by_code)
+        .map(|(language, _)| language.clone())
+}
 
-    serde_json::from_str(json_str).map_err(|e| format!("Failed to parse translation: {}", e))
+/// Every language that can be played, with the name its pack gives it: the
+/// embedded packs, then any `~/.zork-termux/i18n/<code>.json` for a new code.
+/// Packs that fail to load are left out.
+pub fn available_packs() -> Vec<(Language, String)> {
+    let mut languages: Vec<Language> = EMBEDDED
+        .iter()
+        .filter_map(|(code, _)| Language::from_code(code))
+        .collect();
+
+    let mut extra: Vec<Language> = fs::read_dir(user_pack_dir())
+        .into_iter()
+        .flatten()
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .filter_map(|path| Language::from_code(path.file_stem()?.to_str()?))
+        .filter(|lang| !languages.contains(lang))
+        .collect();
+    extra.sort_by(|a, b| a.code().cmp(b.code()));
+    extra.dedup();
+    languages.extend(extra);
+
+    languages
+        .into_iter()
+        .filter_map(|lang| {
+            let user_pack = user_pack_path(lang.code());
+            let packs: Vec<PathBuf> = user_pack
+                .is_file()
+                .then_some(user_pack)
+                .into_iter()
+                .collect();
+            let data = load_layered(&lang, &packs).ok()?;
+            Some((lang, data.meta.name))
+        })
+        .collect()
 }
 
-pub fn load_translation_from_file(path: &Path) -> Result<TranslationData, String> {
-    let content =
-        fs::read_to_string(path).map_err(|e| format!("Failed to read translation file: {}", e))?;
+/// Where players drop packs that extend or override the embedded ones:
+/// `~/.zork-termux/i18n/<code>.json`.
+pub fn user_pack_path(code: &str) -> PathBuf {
+    user_pack_dir().join(format!("{}.json", code))
+}
 
-    serde_json::from_str(&content).map_err(|e| format!("Failed to parse translation: {}", e))
+fn user_pack_dir() -> PathBuf {
+    let home = std::env::var("HOME").unwrap_or_else(|_| ".".to_string());
+    PathBuf::from(home).join(".zork-termux").join("i18n")
 }
 
 pub fn save_translation(data: &TranslationData, path: &Path) -> Result<(), String> {
@@ -29,3 +142,135 @@ pub fn save_translation(data: &TranslationData, path: &Path) -> Result<(), Strin
 
     fs::write(path, content).map_err(|e| format!("Failed to write translation file: {}", e))
 }
+
+fn merge(base: &mut Value, overlay: Value) {
+    match (base, overlay) {
+        (Value::Object(base), Value::Object(overlay)) => {
+            for (key, value) in overlay {
+                match base.get_mut(&key) {
+                    Some(existing) => merge(existing, value),
+                    None => {
+                        base.insert(key, value);
+                    }
+                }
+            }
+        }
+        (base, overlay) => *base = overlay,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_pack(name: &str, content: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "zork-termux-pack-{}-{}.json",
+            name,
+            std::process::id()
+        ));
+        fs::write(&path, content).expect("pack should be written");
+        path
+    }
+
+    #[test]
+    fn packs_merge_key_by_key_over_the_embedded_pack() {
+        let first = write_pack(
+            "first",
+            r#"{
+                "ui": {"welcome": "Bienvenue !"},
+                "rooms": {
+                    "cellar": {"name": "Cave"},
+                    "belfry": {"name": "Beffroi", "description": "Une cloche."}
+                }
+            }"#,
+        );
+        let second = write_pack("second", r#"{"ui": {"goodbye": "Au revoir."}}"#);
+
+        let data = load_layered(&Language::ENGLISH, &[first.clone(), second.clone()])
+            .expect("packs should merge");
+        let english = load_translation(&Language::ENGLISH).expect("embedded pack");
+        assert_eq!(data.ui.welcome, "Bienvenue !");
+        assert_eq!(data.ui.goodbye, "Au revoir.");
+        assert_eq!(data.ui.empty_handed, english.ui.empty_handed);
+        assert_eq!(data.rooms["cellar"].name, "Cave");
+        assert_eq!(
+            data.rooms["cellar"].description,
+            english.rooms["cellar"].description
+        );
+        assert_eq!(data.rooms["belfry"].name, "Beffroi");
+
+        let single = load_translation_from_file(&Language::ITALIAN, &second)
+            .expect("single pack should merge");
+        assert_eq!(single.ui.goodbye, "Au revoir.");
+        assert_eq!(single.meta.language, "it");
+
+        let _ = fs::remove_file(first);
+        let _ = fs::remove_file(second);
+    }
+
+    #[test]
+    fn new_languages_start_from_english() {
+        let french = Language::from_code("fr_FR.UTF-8").expect("code should parse");
+        assert!(load_translation(&french).is_err());
+
+        let pack = write_pack(
+            "fr",
+            r#"{
+                "meta": {"name": "Français"},
+                "ui": {"welcome": "Bienvenue !"},
+                "parser": {"verbs": {"take": ["prends"]}, "quit": ["quitter"]}
+            }"#,
+        );
+        let data = load_translation_from_file(&french, &pack).expect("pack should load");
+        let english = load_translation(&Language::ENGLISH).expect("embedded pack");
+        assert_eq!(data.meta.language, "fr");
+        assert_eq!(data.meta.name, "Français");
+        assert_eq!(data.ui.welcome, "Bienvenue !");
+        assert_eq!(data.ui.goodbye, english.ui.goodbye);
+        assert_eq!(data.parser.quit, ["quitter"]);
+        assert_eq!(data.parser.verbs[&crate::parser::Verb::Take], ["prends"]);
+        assert_eq!(data.games["zork1"].title, english.games["zork1"].title);
+
+        let _ = fs::remove_file(pack);
+    }
+
+    #[test]
+    fn languages_are_named_by_their_packs() {
+        let mut packs: Vec<(Language, String)> = EMBEDDED
+            .iter()
+            .filter_map(|(code, _)| Language::from_code(code))
+            .map(|lang| {
+                let name = load_translation(&lang).expect("embedded pack").meta.name;
+                (lang, name)
+            })
+            .collect();
+        let french = Language::from_code("fr").expect("code should parse");
+        packs.push((french.clone(), "Français".to_string()));
+
+        assert_eq!(language_named(&packs, "italiano"), Some(Language::ITALIAN));
+        assert_eq!(language_named(&packs, "ESPAÑOL"), Some(Language::SPANISH));
+        assert_eq!(
+            language_named(&packs, "en_GB.UTF-8"),
+            Some(Language::ENGLISH)
+        );
+        assert_eq!(language_named(&packs, "français"), Some(french));
+        assert_eq!(language_named(&packs, "klingon"), None);
+    }
+
+    #[test]
+    fn broken_packs_name_the_file() {
+        let broken = write_pack("broken", r#"{"ui": {"welcome": 3}}"#);
+        let err = load_translation_from_file(&Language::ENGLISH, &broken)
+            .expect_err("wrong types should fail");
+        assert!(err.contains(&broken.display().to_string()), "{err}");
+
+        let invalid = write_pack("invalid", "{ not json");
+        let err = load_translation_from_file(&Language::ENGLISH, &invalid)
+            .expect_err("invalid JSON should fail");
+        assert!(err.contains(&invalid.display().to_string()), "{err}");
+
+        let _ = fs::remove_file(broken);
+        let _ = fs::remove_file(invalid);
+    }
+}
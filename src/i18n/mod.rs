@@ -1,43 +1,54 @@
+pub mod coverage;
 pub mod loader;
 
+use crate::parser::Words;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::path::PathBuf;
 
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
-pub enum Language {
-    #[serde(rename = "en")]
-    English,
-    #[serde(rename = "it")]
-    Italian,
-    #[serde(rename = "es")]
-    Spanish,
-}
+/// A language code such as `en`. Any code with a translation pack is
+/// playable: the three embedded ones, or a pack dropped in
+/// `~/.zork-termux/i18n/`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Language(Cow<'static, str>);
 
 impl Language {
-    pub fn code(&self) -> &'static str {
-        match self {
-            Language::English => "en",
-            Language::Italian => "it",
-            Language::Spanish => "es",
-        }
+    pub const ENGLISH: Language = Language(Cow::Borrowed("en"));
+    pub const ITALIAN: Language = Language(Cow::Borrowed("it"));
+    pub const SPANISH: Language = Language(Cow::Borrowed("es"));
+
+    pub fn code(&self) -> &str {
+        &self.0
     }
 
+    /// The language part of a code or locale: `it`, `it_IT.UTF-8` and
+    /// `PT-br` give `it`, `it` and `pt`. Language names are resolved
+    /// through the packs, by `loader::language_named`.
     pub fn from_code(code: &str) -> Option<Self> {
-        let code = code.to_lowercase();
-        let lang_code = code.split('_').next().unwrap_or(&code);
-        match lang_code {
-            "en" | "english" => Some(Language::English),
-            "it" | "italian" | "italiano" => Some(Language::Italian),
-            "es" | "spanish" | "español" => Some(Language::Spanish),
-            _ => None,
+        let code = code
+            .split(['_', '-', '.'])
+            .next()
+            .unwrap_or(code)
+            .to_lowercase();
+        if code.is_empty() || !code.chars().all(|c| c.is_ascii_alphabetic()) {
+            return None;
         }
+        Some(Language(Cow::Owned(code)))
     }
 
     pub fn detect() -> Self {
         std::env::var("LANG")
             .ok()
             .and_then(|l| Language::from_code(&l))
-            .unwrap_or(Language::English)
+            .unwrap_or(Language::ENGLISH)
+    }
+}
+
+impl std::fmt::Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
     }
 }
 
@@ -49,11 +60,21 @@ pub struct TranslationData {
     pub rooms: HashMap<String, RoomTranslation>,
     pub objects: HashMap<String, ObjectTranslation>,
     pub creatures: HashMap<String, CreatureTranslation>,
+    /// Titles and menu lines per game code (`zork1`).
+    #[serde(default)]
+    pub games: HashMap<String, GameTranslation>,
+    /// The parser's vocabulary for this language.
+    #[serde(default)]
+    pub parser: Words,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TranslationMeta {
     pub language: String,
+    /// The language's own name, shown in the language menu. Packs for a new
+    /// language that leave it out are listed by code.
+    #[serde(default)]
+    pub name: String,
     pub version: String,
     pub author: String,
 }
@@ -120,6 +141,72 @@ pub struct UiStrings {
     pub tied: String,
     pub cant_untie: String,
     pub untied: String,
+    pub save_list: String,
+    pub save_entry: String,
+    pub no_saves: String,
+    pub save_deleted: String,
+    pub delete_what: String,
+    pub invalid_save_name: String,
+    pub save_wrong_game: String,
+    pub save_incompatible: String,
+    pub undone: String,
+    pub cant_undo: String,
+    pub give_what: String,
+    pub given: String,
+    pub nothing_to_take: String,
+    pub done: String,
+    pub which_one: String,
+    pub which_option: String,
+    pub which_or: String,
+    pub you_hit: String,
+    pub you_parry: String,
+    pub you_are_hit: String,
+    pub resurrected: String,
+    pub death_options: String,
+    pub rank: String,
+    pub rank_beginner: String,
+    pub rank_amateur: String,
+    pub rank_novice: String,
+    pub rank_junior: String,
+    pub rank_adventurer: String,
+    pub rank_master: String,
+    pub rank_wizard: String,
+    pub rank_master_adventurer: String,
+    pub barrow_opens: String,
+    pub victory_zork1: String,
+    pub victory_zork2: String,
+    pub victory_zork3: String,
+    pub victory_title: String,
+    pub final_score: String,
+    pub victory_options: String,
+    pub is_closed: String,
+    pub cant_lock: String,
+    pub need_key: String,
+    pub wrong_key: String,
+    pub already_locked: String,
+    pub not_locked: String,
+    pub close_first: String,
+    pub locked_done: String,
+    pub light_what: String,
+    pub extinguish_what: String,
+    pub cant_light: String,
+    pub cant_extinguish: String,
+    pub already_lit: String,
+    pub not_lit: String,
+    pub need_flame: String,
+    pub candles_on: String,
+    pub candles_off: String,
+    pub candles_dim: String,
+    pub candles_out: String,
+    pub match_on: String,
+    pub match_off: String,
+    pub match_out: String,
+    pub no_matches: String,
+    pub grue_eats: String,
+    pub use_what: String,
+    pub put_what: String,
+    pub fists: String,
+    pub select_game: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -137,9 +224,14 @@ pub struct HelpStrings {
     pub read: String,
     pub use_: String,
     pub attack: String,
+    pub lock: String,
+    pub tie: String,
+    pub light: String,
     pub score: String,
     pub save: String,
     pub restore: String,
+    pub saves: String,
+    pub undo: String,
     pub quit: String,
 }
 
@@ -159,6 +251,12 @@ pub struct ObjectTranslation {
     pub on_desc: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameTranslation {
+    pub title: String,
+    pub menu: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreatureTranslation {
     pub name: String,
@@ -180,15 +278,33 @@ pub struct I18n {
 
 impl I18n {
     pub fn load(lang: Language) -> Result<Self, String> {
-        let data = loader::load_translation(lang)?;
+        let data = loader::load_translation(&lang)?;
+        Ok(I18n {
+            language: lang,
+            data,
+        })
+    }
+
+    /// The embedded pack for `lang` with translation packs merged on top,
+    /// later packs winning. See `loader::load_layered`.
+    pub fn load_with_packs(lang: Language, packs: &[PathBuf]) -> Result<Self, String> {
+        let data = loader::load_layered(&lang, packs)?;
         Ok(I18n {
             language: lang,
             data,
         })
     }
 
-    pub fn language(&self) -> Language {
-        self.language
+    pub fn language(&self) -> &Language {
+        &self.language
+    }
+
+    pub fn words(&self) -> &Words {
+        &self.data.parser
+    }
+
+    pub fn game(&self, code: &str) -> Option<&GameTranslation> {
+        self.data.games.get(code)
     }
 
     pub fn ui(&self) -> &UiStrings {
@@ -240,6 +356,11 @@ impl I18n {
              ║ {} ║\n\
              ║ {} ║\n\
              ║ {} ║\n\
+             ║ {} ║\n\
+             ║ {} ║\n\
+             ║ {} ║\n\
+             ║ {} ║\n\
+             ║ {} ║\n\
              ╚══════════════════════════════╝",
             h.title,
             h.movement,
@@ -253,19 +374,50 @@ impl I18n {
             h.put,
             h.read,
             h.attack,
+            h.lock,
+            h.tie,
+            h.light,
             h.score,
             h.save,
             h.restore,
+            h.saves,
+            h.undo,
             h.quit
         )
     }
 
-    pub fn score_text(&self, score: u32, moves: u32) -> String {
+    pub fn score_text(&self, score: u32, max_score: u32, moves: u32) -> String {
+        let ui = &self.data.ui;
         format!(
-            "{}: {}\n{}: {}",
-            self.data.ui.score, score, self.data.ui.moves, moves
+            "{}: {} / {}\n{}: {}\n{}: {}",
+            ui.score,
+            score,
+            max_score,
+            ui.moves,
+            moves,
+            ui.rank,
+            self.rank_title(score, max_score)
         )
     }
+
+    /// The classic Zork I ranks, with the thresholds scaled from its 350
+    /// points to `max_score`.
+    pub fn rank_title(&self, score: u32, max_score: u32) -> &str {
+        let ui = &self.data.ui;
+        let scaled = (score.min(max_score) * 350)
+            .checked_div(max_score)
+            .unwrap_or(0);
+        match scaled {
+            350.. => &ui.rank_master_adventurer,
+            331.. => &ui.rank_wizard,
+            301.. => &ui.rank_master,
+            201.. => &ui.rank_adventurer,
+            101.. => &ui.rank_junior,
+            51.. => &ui.rank_novice,
+            26.. => &ui.rank_amateur,
+            _ => &ui.rank_beginner,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -274,8 +426,8 @@ mod tests {
 
     #[test]
     fn advertisement_is_readable_in_all_languages() {
-        for lang in [Language::English, Language::Italian, Language::Spanish] {
-            let i18n = I18n::load(lang).expect("translation should load");
+        for lang in [Language::ENGLISH, Language::ITALIAN, Language::SPANISH] {
+            let i18n = I18n::load(lang.clone()).expect("translation should load");
             let obj = i18n
                 .object("advertisement")
                 .expect("advertisement translation should exist");
@@ -286,4 +438,23 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn ranks_scale_with_the_maximum_score() {
+        let i18n = I18n::load(Language::ENGLISH).expect("translation should load");
+        assert_eq!(i18n.rank_title(0, 350), "Beginner");
+        assert_eq!(i18n.rank_title(60, 350), "Novice Adventurer");
+        assert_eq!(i18n.rank_title(350, 350), "Master Adventurer");
+        assert_eq!(i18n.rank_title(5, 7), "Adventurer");
+        assert_eq!(i18n.rank_title(0, 0), "Beginner");
+        assert!(i18n.score_text(60, 350, 9).contains("60 / 350"));
+    }
+
+    #[test]
+    fn codes_and_locales_are_recognised() {
+        let code = |s| Language::from_code(s).map(|lang| lang.code().to_string());
+        assert_eq!(code("it_IT.UTF-8").as_deref(), Some("it"));
+        assert_eq!(code("PT-br").as_deref(), Some("pt"));
+        assert_eq!(code("klingon!"), None);
+    }
 }
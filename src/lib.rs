@@ -1,4 +1,3 @@
-pub mod data;
 pub mod game;
 pub mod i18n;
 pub mod logging;
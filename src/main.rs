@@ -1,6 +1,9 @@
 use std::io::{self, Write};
-use zork_termux::game::{Game, GameChoice};
-use zork_termux::i18n::{I18n, Language};
+use std::path::PathBuf;
+use zork_termux::game::rng::{Rng, parse_seed};
+use zork_termux::game::world::World;
+use zork_termux::game::{Game, GameChoice, validate};
+use zork_termux::i18n::{I18n, Language, loader};
 use zork_termux::logging;
 
 const MOBILE_DEFAULT_WIDTH: usize = 40;
@@ -8,31 +11,225 @@ const MIN_BOX_WIDTH: usize = 30;
 const MAX_BOX_WIDTH: usize = 56;
 
 fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("validate") => std::process::exit(run_validate(&args[1..])),
+        Some("i18n-coverage") => std::process::exit(run_coverage(&args[1..])),
+        _ => {}
+    }
+
     let session_log = logging::init();
+    let seed = match resolve_seed() {
+        Ok(seed) => seed,
+        Err(e) => {
+            logging::error(format!("cli.seed.invalid error={}", e));
+            eprintln!("{}", e);
+            std::process::exit(2);
+        }
+    };
+
+    let lang_file = match resolve_lang_file() {
+        Ok(path) => path,
+        Err(e) => {
+            logging::error(format!("cli.lang_file.invalid error={}", e));
+            eprintln!("{}", e);
+            std::process::exit(2);
+        }
+    };
 
     let width = detect_box_width();
     print_banner(width);
     print_log_hint(session_log.as_deref());
 
     let language = select_language(width);
-    let game = select_game(&language, width);
+    let packs = translation_packs(&language, lang_file);
+    for pack in &packs {
+        logging::info(format!("i18n.pack path={}", pack.display()));
+    }
+
+    let i18n = match I18n::load_with_packs(language, &packs) {
+        Ok(i18n) => i18n,
+        Err(e) => {
+            logging::error(format!("i18n.load.failed error={}", e));
+            eprintln!("Error loading translations: {}", e);
+            std::process::exit(1);
+        }
+    };
+
+    let game = select_game(&i18n, width);
     logging::info(format!(
         "menu.selection language={} game={:?}",
-        language.code(),
+        i18n.language().code(),
         game
     ));
 
-    match I18n::load(language) {
-        Ok(i18n) => {
-            let mut game = Game::new(i18n, game);
-            game.run();
+    let mut game = Game::new(i18n, game);
+    if let Some(seed) = seed {
+        game = game.with_seed(seed);
+    }
+    game.run();
+}
+
+/// `zork-termux validate [zork1 zork2 zork3]`: checks the named worlds (all
+/// of them by default) and prints one line per problem. Exits 1 when any
+/// world has problems and 2 on an unknown game, so CI can gate on it.
+fn run_validate(codes: &[String]) -> i32 {
+    let mut choices = Vec::new();
+    for code in codes {
+        match GameChoice::from_code(code) {
+            Some(choice) => choices.push(choice),
+            None => {
+                eprintln!("Unknown game `{}` (expected zork1, zork2 or zork3)", code);
+                return 2;
+            }
         }
-        Err(e) => {
-            logging::error(format!("i18n.load.failed error={}", e));
-            eprintln!("Error loading translations: {}", e);
-            std::process::exit(1);
+    }
+    if choices.is_empty() {
+        choices = GameChoice::ALL.to_vec();
+    }
+
+    let mut failed = false;
+    for choice in choices {
+        let world = World::load(choice);
+        let problems = validate::check(&world);
+        if problems.is_empty() {
+            println!(
+                "{}: ok ({} rooms, {} objects, {} creatures)",
+                choice.code(),
+                world.rooms.len(),
+                world.objects.len(),
+                world.creatures.len()
+            );
+            continue;
+        }
+
+        failed = true;
+        println!("{}: {} problems", choice.code(), problems.len());
+        for problem in problems {
+            println!("  {}", problem);
+        }
+    }
+
+    i32::from(failed)
+}
+
+/// `zork-termux i18n-coverage [--json] [en it es]`: reports, per language,
+/// the world ids a pack is missing, the fields that still read as English
+/// and the keys no world uses. `--json` prints the reports as a JSON array.
+/// With no codes every available pack is checked, user packs included.
+fn run_coverage(args: &[String]) -> i32 {
+    let packs = loader::available_packs();
+    let mut json = false;
+    let mut languages = Vec::new();
+    for arg in args {
+        if arg == "--json" {
+            json = true;
+            continue;
+        }
+        match loader::language_named(&packs, arg) {
+            Some(language) => languages.push(language),
+            None => {
+                eprintln!(
+                    "Unknown language `{}` (expected a code such as en or a pack's name)",
+                    arg
+                );
+                return 2;
+            }
+        }
+    }
+    if languages.is_empty() {
+        languages = packs.into_iter().map(|(language, _)| language).collect();
+    }
+
+    let worlds: Vec<World> = GameChoice::ALL.into_iter().map(World::load).collect();
+    let mut reports = Vec::new();
+    for language in languages {
+        let packs = translation_packs(&language, None);
+        match I18n::load_with_packs(language, &packs) {
+            Ok(i18n) => reports.push(i18n.coverage(&worlds)),
+            Err(e) => {
+                eprintln!("Error loading translations: {}", e);
+                return 1;
+            }
+        }
+    }
+
+    if json {
+        match serde_json::to_string_pretty(&reports) {
+            Ok(text) => println!("{}", text),
+            Err(e) => {
+                eprintln!("Failed to serialize coverage: {}", e);
+                return 1;
+            }
         }
+        return 0;
     }
+
+    for report in reports {
+        println!(
+            "{}: {} missing, {} untranslated, {} orphaned",
+            report.language,
+            report.missing.len(),
+            report.untranslated.len(),
+            report.orphaned.len()
+        );
+        for (label, keys) in [
+            ("missing", &report.missing),
+            ("untranslated", &report.untranslated),
+            ("orphaned", &report.orphaned),
+        ] {
+            for key in keys {
+                println!("  {:<12} {}", label, key);
+            }
+        }
+    }
+    0
+}
+
+/// `--seed N` (or `--seed=N`) wins over `ZORK_SEED`; with neither the game
+/// picks a seed from the clock.
+fn resolve_seed() -> Result<Option<u64>, String> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--seed" {
+            let value = args.next().ok_or("--seed needs a value")?;
+            return parse_seed(&value).map(Some);
+        }
+        if let Some(value) = arg.strip_prefix("--seed=") {
+            return parse_seed(value).map(Some);
+        }
+    }
+
+    Rng::seed_from_env()
+}
+
+/// `--lang-file PATH` (or `--lang-file=PATH`): a translation pack to merge
+/// over the chosen language.
+fn resolve_lang_file() -> Result<Option<PathBuf>, String> {
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--lang-file" {
+            let value = args.next().ok_or("--lang-file needs a path")?;
+            return Ok(Some(PathBuf::from(value)));
+        }
+        if let Some(value) = arg.strip_prefix("--lang-file=") {
+            return Ok(Some(PathBuf::from(value)));
+        }
+    }
+
+    Ok(None)
+}
+
+/// Packs merged over the embedded translation: the player's
+/// `~/.zork-termux/i18n/<code>.json` if present, then `--lang-file`.
+fn translation_packs(language: &Language, lang_file: Option<PathBuf>) -> Vec<PathBuf> {
+    let user_pack = loader::user_pack_path(language.code());
+    user_pack
+        .is_file()
+        .then_some(user_pack)
+        .into_iter()
+        .chain(lang_file)
+        .collect()
 }
 
 fn detect_box_width() -> usize {
@@ -66,62 +263,49 @@ fn print_log_hint(log_path: Option<&std::path::Path>) {
     }
 }
 
+/// Lists every available pack; the choice may be typed as its number, code
+/// or name. Anything else takes the detected language, or English when
+/// there is no pack for it.
 fn select_language(width: usize) -> Language {
-    let detected = Language::detect();
-
-    print_box(
-        width,
-        "LANGUAGE / LINGUA / IDIOMA",
-        &[
-            "[1] English".to_string(),
-            "[2] Italiano".to_string(),
-            "[3] Espanol".to_string(),
-            format!("[Enter] Auto ({})", detected.code().to_uppercase()),
-        ],
-    );
+    let packs = loader::available_packs();
+    let detected = Some(Language::detect())
+        .filter(|detected| packs.iter().any(|(language, _)| language == detected))
+        .unwrap_or(Language::ENGLISH);
+
+    let mut options: Vec<String> = packs
+        .iter()
+        .enumerate()
+        .map(|(index, (_, name))| format!("[{}] {}", index + 1, name))
+        .collect();
+    options.push(format!("[Enter] Auto ({})", detected.code().to_uppercase()));
+    print_box(width, "LANGUAGE / LINGUA / IDIOMA", &options);
 
     let input = read_prompt();
+    let input = input.trim().to_lowercase();
 
-    match input.trim() {
-        "1" | "en" | "english" => Language::English,
-        "2" | "it" | "italiano" => Language::Italian,
-        "3" | "es" | "español" => Language::Spanish,
-        "" => detected,
-        _ => detected, // fallback keeps mobile menu fast and forgiving
-    }
-}
-
-fn select_game(lang: &Language, width: usize) -> GameChoice {
-    let (title, options) = match lang {
-        Language::English => (
-            "SELECT GAME",
-            vec![
-                "[1] Zork I  - Great Underground Empire",
-                "[2] Zork II - Wizard of Frobozz",
-                "[3] Zork III - The Dungeon Master",
-            ],
-        ),
-        Language::Italian => (
-            "SCEGLI GIOCO",
-            vec![
-                "[1] Zork I  - Grande Impero Sotterraneo",
-                "[2] Zork II - Il Mago di Frobozz",
-                "[3] Zork III - Il Dungeon Master",
-            ],
-        ),
-        Language::Spanish => (
-            "ELIGE JUEGO",
-            vec![
-                "[1] Zork I  - Gran Imperio Subterraneo",
-                "[2] Zork II - El Mago de Frobozz",
-                "[3] Zork III - El Dungeon Master",
-            ],
-        ),
-    };
+    input
+        .parse::<usize>()
+        .ok()
+        .and_then(|number| packs.get(number.checked_sub(1)?))
+        .map(|(language, _)| language.clone())
+        .or_else(|| loader::language_named(&packs, &input))
+        .unwrap_or(detected) // fallback keeps mobile menu fast and forgiving
+}
+
+fn select_game(i18n: &I18n, width: usize) -> GameChoice {
+    let options = GameChoice::ALL
+        .iter()
+        .enumerate()
+        .map(|(index, choice)| {
+            let menu = i18n
+                .game(choice.code())
+                .map_or(choice.short_title(), |game| game.menu.as_str());
+            format!("[{}] {}", index + 1, menu)
+        })
+        .collect::<Vec<_>>();
 
     println!();
-    let option_lines = options.iter().map(|s| s.to_string()).collect::<Vec<_>>();
-    print_box(width, title, &option_lines);
+    print_box(width, &i18n.ui().select_game, &options);
 
     let input = read_prompt();
 
@@ -1,15 +1,71 @@
+use serde::{Deserialize, Serialize};
+
+/// A parsed clause: `verb [direct] [preposition indirect]`, e.g.
+/// `attack troll with sword` or `metti la moneta nella cassetta`.
 #[derive(Debug, Clone)]
 pub struct Command {
     pub verb: Verb,
-    pub object: Option<String>,
+    pub direct: Option<String>,
+    pub preposition: Option<Preposition>,
+    pub indirect: Option<String>,
+    /// Set when the direct object names several things (`all`, `x and y`).
+    pub selection: Option<Selection>,
 }
 
-#[derive(Debug, Clone)]
+impl Command {
+    pub fn new(verb: Verb) -> Self {
+        Command {
+            verb,
+            direct: None,
+            preposition: None,
+            indirect: None,
+            selection: None,
+        }
+    }
+
+    pub fn with_direct(mut self, direct: &str) -> Self {
+        self.direct = Some(direct.to_string());
+        self
+    }
+
+    pub fn with_indirect(mut self, preposition: Preposition, indirect: &str) -> Self {
+        self.preposition = Some(preposition);
+        self.indirect = Some(indirect.to_string());
+        self
+    }
+}
+
+/// A multi-object direct object: `take all except lamp`,
+/// `posa spada e lampada`, `toma todo`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Selection {
+    All { except: Vec<String> },
+    Items(Vec<String>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Preposition {
+    With,
+    In,
+    On,
+    To,
+    From,
+    At,
+}
+
+/// Translation packs name verbs in snake case (`north_east`, `take`).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Verb {
     North,
     South,
     East,
     West,
+    NorthEast,
+    NorthWest,
+    SouthEast,
+    SouthWest,
     Up,
     Down,
     Look,
@@ -25,8 +81,54 @@ pub enum Verb {
     Score,
     Save,
     Restore,
+    Saves,
+    Delete,
+    Undo,
     Attack,
     Put,
     Enter,
+    Give,
+    Lock,
+    Unlock,
+    Eat,
+    Drink,
+    Climb,
+    Tie,
+    Untie,
+    Light,
+    Extinguish,
+    #[serde(skip)]
     Unknown(String),
 }
+
+impl Verb {
+    pub fn is_direction(&self) -> bool {
+        matches!(
+            self,
+            Verb::North
+                | Verb::South
+                | Verb::East
+                | Verb::West
+                | Verb::NorthEast
+                | Verb::NorthWest
+                | Verb::SouthEast
+                | Verb::SouthWest
+                | Verb::Up
+                | Verb::Down
+        )
+    }
+
+    /// Session commands that neither change the world nor spend game time.
+    pub fn is_meta(&self) -> bool {
+        matches!(
+            self,
+            Verb::Help | Verb::Score | Verb::Save | Verb::Saves | Verb::Delete | Verb::Undo
+        )
+    }
+
+    /// Whether the command spends a turn, letting timers and NPCs advance.
+    /// Restoring replaces the clock, and unknown words are not actions.
+    pub fn takes_time(&self) -> bool {
+        !self.is_meta() && !matches!(self, Verb::Restore | Verb::Unknown(_))
+    }
+}
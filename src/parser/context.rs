@@ -0,0 +1,23 @@
+/// What the player referred to last, so `read it`, `esaminala` or
+/// `enciéndela` can name it again. `Game` carries one and feeds it the
+/// referents of every command that succeeds.
+#[derive(Debug, Clone, Default)]
+pub struct ParserContext {
+    referents: Vec<String>,
+}
+
+impl ParserContext {
+    pub fn remember(&mut self, referents: &[String]) {
+        if !referents.is_empty() {
+            self.referents = referents.to_vec();
+        }
+    }
+
+    pub fn referents(&self) -> &[String] {
+        &self.referents
+    }
+
+    pub fn clear(&mut self) {
+        self.referents.clear();
+    }
+}
@@ -1,136 +1,416 @@
 pub mod command;
+pub mod context;
+pub mod words;
 
-use crate::i18n::Language;
-pub use command::{Command, Verb};
+pub use command::{Command, Preposition, Selection, Verb};
+pub use context::ParserContext;
+use std::collections::HashMap;
+pub use words::Words;
 
 pub struct Parser {
-    language: Language,
+    words: Words,
+    verbs: HashMap<String, Verb>,
+    prepositions: HashMap<String, Preposition>,
 }
 
 impl Parser {
-    pub fn new(language: Language) -> Self {
-        Parser { language }
+    /// A parser for the language whose vocabulary is `words`, usually
+    /// `I18n::words()`.
+    pub fn new(words: &Words) -> Self {
+        fn index<T: Clone>(table: &HashMap<T, Vec<String>>) -> HashMap<String, T> {
+            table
+                .iter()
+                .flat_map(|(meaning, spellings)| {
+                    spellings
+                        .iter()
+                        .map(move |word| (word.clone(), meaning.clone()))
+                })
+                .collect()
+        }
+
+        Parser {
+            words: words.clone(),
+            verbs: index(&words.verbs),
+            prepositions: index(&words.prepositions),
+        }
     }
 
     pub fn parse(&self, input: &str) -> Option<Command> {
-        let tokens: Vec<&str> = input.split_whitespace().collect();
+        self.parse_in_context(input, &ParserContext::default())
+    }
+
+    /// Parses one clause, resolving pronouns against what the player last
+    /// referred to.
+    pub fn parse_in_context(&self, input: &str, context: &ParserContext) -> Option<Command> {
+        let tokens = self.tokenize(input);
 
         if tokens.is_empty() {
             return None;
         }
 
-        let verb = self.parse_verb(tokens[0]);
-        let object = if tokens.len() > 1 {
-            Some(tokens[1..].join(" "))
-        } else {
-            None
+        let mut verb = self.parse_verb(&tokens[0]);
+        let mut rest: Vec<&str> = tokens[1..].iter().map(String::as_str).collect();
+
+        // `esaminala`, `enciéndela`: the pronoun rides on the verb.
+        if matches!(verb, Verb::Unknown(_))
+            && let Some((stem_verb, pronoun)) = self.split_enclitic(&tokens[0])
+        {
+            verb = stem_verb;
+            rest.insert(0, pronoun);
+        }
+
+        // `turn on the lamp`, `switch it off`, `blow out the candles`.
+        if let Some(switched) = self.switch_phrase(&tokens[0], &mut rest) {
+            verb = switched;
+        }
+
+        // `chiudi a chiave`, `cierra con llave`: closing with a key is locking.
+        if matches!(verb, Verb::Close) && self.is_key_phrase(&rest) {
+            verb = Verb::Lock;
+            rest.drain(..2);
+        }
+
+        if matches!(verb, Verb::Enter)
+            && let Some(direction) = self.parse_go_direction(&rest)
+        {
+            return Some(Command::new(direction));
+        }
+
+        // Slot names are free text, not noun phrases.
+        if matches!(verb, Verb::Save | Verb::Restore | Verb::Delete) {
+            let mut cmd = Command::new(verb);
+            cmd.direct = (!rest.is_empty()).then(|| rest.join(" "));
+            return Some(cmd);
+        }
+
+        let mut cmd = self.parse_clause(verb, &rest);
+        self.resolve_pronouns(&mut cmd, context);
+
+        if cmd.selection.is_none() && matches!(cmd.verb, Verb::Take | Verb::Drop | Verb::Put) {
+            cmd.selection = cmd
+                .direct
+                .as_deref()
+                .and_then(|phrase| self.parse_selection(phrase));
+        }
+
+        // `look at lamp` / `guarda la lampada` is examining.
+        if matches!(cmd.verb, Verb::Look) && cmd.direct.is_some() {
+            cmd.verb = Verb::Examine;
+        }
+
+        Some(cmd)
+    }
+
+    /// A bare noun phrase, as typed in answer to "Which do you mean?".
+    pub fn parse_noun(&self, input: &str) -> Option<String> {
+        let tokens = self.tokenize(input);
+        let words: Vec<&str> = tokens.iter().map(String::as_str).collect();
+        self.noun_phrase(&words)
+    }
+
+    /// Splits one input line into clauses at periods, commas, semicolons and
+    /// the language's sequencing words, so `take lamp. n, open trap door` and
+    /// `prendi lampada poi nord` run as several commands. A dangling `and` /
+    /// `e` / `y` before the break (`e poi`, `y luego`) is dropped.
+    pub fn split_clauses(&self, input: &str) -> Vec<String> {
+        let (sequencers, joiners) = (&self.words.sequencers, &self.words.joiners);
+
+        let mut clauses = Vec::new();
+        for segment in input.split(['.', ',', ';']) {
+            let mut words: Vec<&str> = Vec::new();
+            for word in segment.split_whitespace() {
+                if sequencers.contains(&word.to_lowercase()) {
+                    push_clause(&mut clauses, &mut words, joiners);
+                } else {
+                    words.push(word);
+                }
+            }
+            push_clause(&mut clauses, &mut words, joiners);
+        }
+        clauses
+    }
+
+    /// Splits the words after the verb into direct object, preposition and
+    /// indirect object. A preposition right after the verb is a particle
+    /// (`look at`, `entra nella`) and is dropped.
+    fn parse_clause(&self, verb: Verb, rest: &[&str]) -> Command {
+        let mut words = rest;
+        while let Some((first, tail)) = words.split_first() {
+            if self.parse_preposition(first).is_none() {
+                break;
+            }
+            words = tail;
+        }
+
+        let split = words
+            .iter()
+            .enumerate()
+            .skip(1)
+            .find_map(|(i, word)| self.parse_preposition(word).map(|prep| (i, prep)));
+
+        let mut cmd = Command::new(verb);
+        match split {
+            Some((i, prep)) => {
+                cmd.direct = self.noun_phrase(&words[..i]);
+                cmd.indirect = self.noun_phrase(&words[i + 1..]);
+                if cmd.indirect.is_some() {
+                    cmd.preposition = Some(prep);
+                }
+            }
+            None => cmd.direct = self.noun_phrase(words),
+        }
+        cmd
+    }
+
+    /// Recognizes `all [except ...]` and `x and y` lists in a direct object.
+    /// A single plain noun phrase is not a selection.
+    fn parse_selection(&self, phrase: &str) -> Option<Selection> {
+        let Words {
+            all,
+            except,
+            joiners,
+            ..
+        } = &self.words;
+
+        let words: Vec<&str> = phrase.split_whitespace().collect();
+        let list = |words: &[&str]| -> Vec<String> {
+            words
+                .split(|word| contains(joiners, word))
+                .filter_map(|item| self.noun_phrase(item))
+                .collect()
         };
 
-        Some(Command { verb, object })
+        if let Some((first, tail)) = words.split_first()
+            && contains(all, first)
+        {
+            let tail = match tail.split_first() {
+                Some((word, rest)) if contains(except, word) => rest,
+                _ => tail,
+            };
+            return Some(Selection::All { except: list(tail) });
+        }
+
+        let items = list(&words);
+        (items.len() > 1).then_some(Selection::Items(items))
+    }
+
+    /// Drops leading articles; `None` when nothing is left. A lone pronoun
+    /// is kept even where it doubles as an article (`prendi la`).
+    fn noun_phrase(&self, words: &[&str]) -> Option<String> {
+        if let [word] = words
+            && self.is_pronoun(word)
+        {
+            return Some(word.to_string());
+        }
+
+        let start = words
+            .iter()
+            .position(|word| !self.is_article(word))
+            .unwrap_or(words.len());
+
+        let phrase = words[start..].join(" ");
+        (!phrase.is_empty()).then_some(phrase)
+    }
+
+    /// Whitespace tokens, lowercased. Elisions (`l'acqua`, `nell'armadio`)
+    /// are split after the mark, which becomes a plain `'`.
+    fn tokenize(&self, input: &str) -> Vec<String> {
+        let mut input = input.to_lowercase();
+        for mark in &self.words.elisions {
+            input = input.replace(mark.as_str(), "' ");
+        }
+        input.split_whitespace().map(str::to_string).collect()
+    }
+
+    /// Replaces pronoun objects with the remembered referents. With nothing
+    /// to refer to the object is dropped, so the verb asks `Take what?`.
+    fn resolve_pronouns(&self, cmd: &mut Command, context: &ParserContext) {
+        let referents = context.referents();
+
+        if cmd
+            .direct
+            .as_deref()
+            .is_some_and(|word| self.is_pronoun(word))
+        {
+            cmd.direct = referents.last().cloned();
+            if referents.len() > 1 && matches!(cmd.verb, Verb::Take | Verb::Drop | Verb::Put) {
+                cmd.selection = Some(Selection::Items(referents.to_vec()));
+            }
+        }
+
+        if cmd
+            .indirect
+            .as_deref()
+            .is_some_and(|word| self.is_pronoun(word))
+        {
+            cmd.indirect = referents.last().cloned();
+            if cmd.indirect.is_none() {
+                cmd.preposition = None;
+            }
+        }
+    }
+
+    fn is_pronoun(&self, word: &str) -> bool {
+        contains(&self.words.pronouns, word)
+    }
+
+    /// Splits an imperative with an attached object pronoun into its verb and
+    /// the pronoun: `prendila` → take + `la`, `cógelo` → take + `lo`. Spanish
+    /// adds a stress accent to the stem, which is dropped before lookup.
+    fn split_enclitic<'a>(&self, word: &'a str) -> Option<(Verb, &'a str)> {
+        self.words.enclitics.iter().find_map(|suffix| {
+            let stem = word.strip_suffix(suffix)?;
+            let stem: String = stem
+                .chars()
+                .map(|ch| match ch {
+                    'á' => 'a',
+                    'é' => 'e',
+                    'í' => 'i',
+                    'ó' => 'o',
+                    'ú' => 'u',
+                    _ => ch,
+                })
+                .collect();
+            let verb = self.parse_verb(&stem);
+            let usable = !matches!(verb, Verb::Unknown(_)) && !verb.is_direction();
+            usable.then(|| (verb, &word[word.len() - suffix.len()..]))
+        })
+    }
+
+    /// Phrasal verbs such as `turn on`. The particle may come first
+    /// (`turn on lamp`) or last (`turn lamp on`) and is removed from `rest`.
+    fn switch_phrase(&self, word: &str, rest: &mut Vec<&str>) -> Option<Verb> {
+        let particles = self.words.phrasal.get(word)?;
+
+        particles.iter().find_map(|(particle, verb)| {
+            let particle = particle.as_str();
+            if rest.first() == Some(&particle) {
+                rest.remove(0);
+            } else if rest.len() > 1 && rest.last() == Some(&particle) {
+                rest.pop();
+            } else {
+                return None;
+            }
+            Some(verb.clone())
+        })
+    }
+
+    fn is_key_phrase(&self, words: &[&str]) -> bool {
+        let phrase = &self.words.lock_phrase;
+        !phrase.is_empty()
+            && words.len() >= phrase.len()
+            && words.iter().zip(phrase).all(|(a, b)| a == b)
+    }
+
+    fn is_article(&self, word: &str) -> bool {
+        contains(&self.words.articles, word)
+    }
+
+    fn parse_preposition(&self, word: &str) -> Option<Preposition> {
+        self.prepositions.get(word).copied()
+    }
+
+    /// `go north`, `vai a nord`, `ir hacia el norte`: the enter/go verbs
+    /// followed by a direction are plain movement.
+    fn parse_go_direction(&self, rest: &[&str]) -> Option<Verb> {
+        let (last, middle) = rest.split_last()?;
+
+        if !middle
+            .iter()
+            .all(|word| contains(&self.words.go_fillers, word))
+        {
+            return None;
+        }
+
+        let direction = self.parse_verb(last);
+        direction.is_direction().then_some(direction)
     }
 
     fn parse_verb(&self, word: &str) -> Verb {
         let word = word.to_lowercase();
+        self.verbs
+            .get(&word)
+            .cloned()
+            .unwrap_or(Verb::Unknown(word))
+    }
+}
 
-        match self.language {
-            Language::English => self.parse_verb_en(&word),
-            Language::Italian => self.parse_verb_it(&word),
-            Language::Spanish => self.parse_verb_es(&word),
-        }
-    }
-
-    fn parse_verb_en(&self, word: &str) -> Verb {
-        match word {
-            "n" | "north" => Verb::North,
-            "s" | "south" => Verb::South,
-            "e" | "east" => Verb::East,
-            "w" | "west" => Verb::West,
-            "u" | "up" => Verb::Up,
-            "d" | "down" => Verb::Down,
-            "l" | "look" => Verb::Look,
-            "i" | "inv" | "inventory" => Verb::Inventory,
-            "take" | "get" | "pick" => Verb::Take,
-            "drop" => Verb::Drop,
-            "x" | "examine" | "ex" => Verb::Examine,
-            "open" => Verb::Open,
-            "close" => Verb::Close,
-            "r" | "read" => Verb::Read,
-            "use" => Verb::Use,
-            "attack" | "kill" | "hit" | "fight" => Verb::Attack,
-            "put" | "insert" | "place" => Verb::Put,
-            "save" => Verb::Save,
-            "restore" | "load" => Verb::Restore,
-            "help" | "?" => Verb::Help,
-            "score" => Verb::Score,
-            "enter" | "in" | "go" => Verb::Enter,
-            _ => Verb::Unknown(word.to_string()),
-        }
-    }
-
-    fn parse_verb_it(&self, word: &str) -> Verb {
-        match word {
-            "n" | "nord" => Verb::North,
-            "s" | "sud" => Verb::South,
-            "e" | "est" => Verb::East,
-            "o" | "ovest" => Verb::West,
-            "su" | "alto" => Verb::Up,
-            "giu" | "giù" | "basso" => Verb::Down,
-            "l" | "guarda" | "look" => Verb::Look,
-            "i" | "inv" | "inventario" => Verb::Inventory,
-            "prendi" | "take" | "raccogli" => Verb::Take,
-            "posa" | "drop" | "lascia" => Verb::Drop,
-            "x" | "esamina" | "ex" => Verb::Examine,
-            "apri" | "open" => Verb::Open,
-            "chiudi" | "close" => Verb::Close,
-            "r" | "leggi" | "read" => Verb::Read,
-            "usa" | "use" => Verb::Use,
-            "attacca" | "uccidi" | "colpisci" | "attack" | "kill" => Verb::Attack,
-            "metti" | "inserisci" | "put" => Verb::Put,
-            "salva" | "save" => Verb::Save,
-            "ripristina" | "carica" | "restore" | "load" => Verb::Restore,
-            "aiuto" | "?" | "help" => Verb::Help,
-            "punti" | "score" => Verb::Score,
-            "entra" | "in" | "vai" => Verb::Enter,
-            _ => Verb::Unknown(word.to_string()),
-        }
-    }
-
-    fn parse_verb_es(&self, word: &str) -> Verb {
-        match word {
-            "n" | "norte" => Verb::North,
-            "s" | "sur" => Verb::South,
-            "e" | "este" => Verb::East,
-            "o" | "oeste" => Verb::West,
-            "arriba" | "subir" => Verb::Up,
-            "abajo" | "bajar" => Verb::Down,
-            "l" | "mirar" | "look" => Verb::Look,
-            "i" | "inv" | "inventario" => Verb::Inventory,
-            "tomar" | "coger" | "take" => Verb::Take,
-            "soltar" | "drop" => Verb::Drop,
-            "x" | "examinar" | "ex" => Verb::Examine,
-            "abrir" | "open" => Verb::Open,
-            "cerrar" | "close" => Verb::Close,
-            "r" | "leer" | "read" => Verb::Read,
-            "usar" | "use" => Verb::Use,
-            "atacar" | "matar" | "golpear" | "attack" | "kill" => Verb::Attack,
-            "poner" | "mete" | "insertar" | "put" => Verb::Put,
-            "guardar" | "save" => Verb::Save,
-            "restaurar" | "cargar" | "restore" | "load" => Verb::Restore,
-            "ayuda" | "?" | "help" => Verb::Help,
-            "puntos" | "score" => Verb::Score,
-            "entrar" | "entra" | "en" | "ir" => Verb::Enter,
-            _ => Verb::Unknown(word.to_string()),
-        }
+fn contains(words: &[String], word: &str) -> bool {
+    words.iter().any(|known| known == word)
+}
+
+/// A lone joiner is kept: in Italian `e` is also east.
+fn push_clause(clauses: &mut Vec<String>, words: &mut Vec<&str>, joiners: &[String]) {
+    if words.len() > 1
+        && let Some(last) = words.last()
+        && contains(joiners, &last.to_lowercase())
+    {
+        words.pop();
+    }
+    if !words.is_empty() {
+        clauses.push(words.join(" "));
     }
+    words.clear();
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::i18n::{I18n, Language};
+
+    fn parser(language: Language) -> Parser {
+        let i18n = I18n::load(language).expect("translation should load");
+        Parser::new(i18n.words())
+    }
+
+    #[test]
+    fn no_word_means_two_verbs() {
+        for language in [Language::ENGLISH, Language::ITALIAN, Language::SPANISH] {
+            let i18n = I18n::load(language.clone()).expect("translation should load");
+            let words = i18n.words();
+            let total: usize = words.verbs.values().map(Vec::len).sum();
+            assert_eq!(Parser::new(words).verbs.len(), total, "{}", language);
+            let total: usize = words.prepositions.values().map(Vec::len).sum();
+            assert_eq!(Parser::new(words).prepositions.len(), total, "{}", language);
+        }
+    }
+
+    #[test]
+    fn a_new_language_is_only_data() {
+        let words: Words = serde_json::from_str(
+            r#"{
+                "verbs": {"take": ["prends"], "north": ["n", "nord"], "enter": ["va"]},
+                "prepositions": {"in": ["dans"]},
+                "articles": ["le", "la", "l'"],
+                "pronouns": ["le", "la"],
+                "sequencers": ["puis"],
+                "joiners": ["et"],
+                "all": ["tout"],
+                "except": ["sauf"],
+                "go_fillers": ["au"],
+                "elisions": ["'"]
+            }"#,
+        )
+        .expect("words should deserialize");
+        let parser = Parser::new(&words);
+
+        let cmd = parser.parse("prends l'epee").unwrap();
+        assert_eq!(cmd.verb, Verb::Take);
+        assert_eq!(cmd.direct.as_deref(), Some("epee"));
+        assert_eq!(parser.parse("va au nord").unwrap().verb, Verb::North);
+        assert_eq!(
+            parser.split_clauses("prends tout sauf la lampe puis nord"),
+            vec!["prends tout sauf la lampe", "nord"]
+        );
+        assert!(matches!(
+            parser.parse("dance").unwrap().verb,
+            Verb::Unknown(_)
+        ));
+    }
 
     #[test]
     fn parses_missing_english_verbs() {
-        let parser = Parser::new(Language::English);
+        let parser = parser(Language::ENGLISH);
         assert!(matches!(parser.parse("save").unwrap().verb, Verb::Save));
         assert!(matches!(
             parser.parse("restore").unwrap().verb,
@@ -153,7 +433,7 @@ mod tests {
 
     #[test]
     fn parses_missing_italian_verbs() {
-        let parser = Parser::new(Language::Italian);
+        let parser = parser(Language::ITALIAN);
         assert!(matches!(parser.parse("salva").unwrap().verb, Verb::Save));
         assert!(matches!(
             parser.parse("ripristina").unwrap().verb,
@@ -179,7 +459,7 @@ mod tests {
 
     #[test]
     fn parses_missing_spanish_verbs() {
-        let parser = Parser::new(Language::Spanish);
+        let parser = parser(Language::SPANISH);
         assert!(matches!(parser.parse("guardar").unwrap().verb, Verb::Save));
         assert!(matches!(
             parser.parse("restaurar").unwrap().verb,
@@ -203,10 +483,315 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn parses_diagonal_directions() {
+        let en = parser(Language::ENGLISH);
+        assert!(matches!(en.parse("ne").unwrap().verb, Verb::NorthEast));
+        assert!(matches!(
+            en.parse("southwest").unwrap().verb,
+            Verb::SouthWest
+        ));
+
+        let it = parser(Language::ITALIAN);
+        assert!(matches!(it.parse("no").unwrap().verb, Verb::NorthWest));
+        assert!(matches!(it.parse("sudest").unwrap().verb, Verb::SouthEast));
+
+        let es = parser(Language::SPANISH);
+        assert!(matches!(es.parse("noreste").unwrap().verb, Verb::NorthEast));
+        assert!(matches!(es.parse("so").unwrap().verb, Verb::SouthWest));
+    }
+
+    #[test]
+    fn go_with_direction_is_movement() {
+        let en = parser(Language::ENGLISH);
+        let cmd = en.parse("go northeast").unwrap();
+        assert!(matches!(cmd.verb, Verb::NorthEast));
+        assert!(cmd.direct.is_none());
+        assert!(matches!(
+            en.parse("go to the north").unwrap().verb,
+            Verb::North
+        ));
+        assert!(matches!(en.parse("go window").unwrap().verb, Verb::Enter));
+
+        let it = parser(Language::ITALIAN);
+        assert!(matches!(it.parse("vai a nord").unwrap().verb, Verb::North));
+        assert!(matches!(
+            it.parse("vai nordovest").unwrap().verb,
+            Verb::NorthWest
+        ));
+
+        let es = parser(Language::SPANISH);
+        assert!(matches!(
+            es.parse("ir hacia el sur").unwrap().verb,
+            Verb::South
+        ));
+    }
+
+    #[test]
+    fn parses_direct_preposition_indirect() {
+        let en = parser(Language::ENGLISH);
+        let cmd = en.parse("attack the troll with the sword").unwrap();
+        assert!(matches!(cmd.verb, Verb::Attack));
+        assert_eq!(cmd.direct.as_deref(), Some("troll"));
+        assert_eq!(cmd.preposition, Some(Preposition::With));
+        assert_eq!(cmd.indirect.as_deref(), Some("sword"));
+
+        let cmd = en.parse("unlock grate with key").unwrap();
+        assert_eq!(cmd.direct.as_deref(), Some("grate"));
+        assert_eq!(cmd.indirect.as_deref(), Some("key"));
+
+        let cmd = en.parse("give garlic to thief").unwrap();
+        assert!(matches!(cmd.verb, Verb::Give));
+        assert_eq!(cmd.preposition, Some(Preposition::To));
+        assert_eq!(cmd.indirect.as_deref(), Some("thief"));
+
+        let it = parser(Language::ITALIAN);
+        let cmd = it.parse("metti la moneta nella cassetta").unwrap();
+        assert!(matches!(cmd.verb, Verb::Put));
+        assert_eq!(cmd.direct.as_deref(), Some("moneta"));
+        assert_eq!(cmd.preposition, Some(Preposition::In));
+        assert_eq!(cmd.indirect.as_deref(), Some("cassetta"));
+
+        let cmd = it.parse("metti l'acqua nell'ampolla").unwrap();
+        assert_eq!(cmd.direct.as_deref(), Some("acqua"));
+        assert_eq!(cmd.indirect.as_deref(), Some("ampolla"));
+
+        let es = parser(Language::SPANISH);
+        let cmd = es.parse("atacar al troll con la espada").unwrap();
+        assert_eq!(cmd.direct.as_deref(), Some("troll"));
+        assert_eq!(cmd.preposition, Some(Preposition::With));
+        assert_eq!(cmd.indirect.as_deref(), Some("espada"));
+    }
+
+    #[test]
+    fn leading_preposition_is_a_particle() {
+        let en = parser(Language::ENGLISH);
+        let cmd = en.parse("look at the lamp").unwrap();
+        assert!(matches!(cmd.verb, Verb::Examine));
+        assert_eq!(cmd.direct.as_deref(), Some("lamp"));
+        assert!(cmd.preposition.is_none());
+
+        let it = parser(Language::ITALIAN);
+        let cmd = it.parse("entra nella finestra").unwrap();
+        assert!(matches!(cmd.verb, Verb::Enter));
+        assert_eq!(cmd.direct.as_deref(), Some("finestra"));
+    }
+
     #[test]
     fn parses_multiword_object() {
-        let parser = Parser::new(Language::English);
+        let parser = parser(Language::ENGLISH);
         let cmd = parser.parse("take rusty brass key").unwrap();
-        assert_eq!(cmd.object.as_deref(), Some("rusty brass key"));
+        assert_eq!(cmd.direct.as_deref(), Some("rusty brass key"));
+    }
+
+    #[test]
+    fn splits_chained_clauses() {
+        let en = parser(Language::ENGLISH);
+        assert_eq!(
+            en.split_clauses("take lamp. n, open trap door and then down"),
+            vec!["take lamp", "n", "open trap door", "down"]
+        );
+        assert_eq!(en.split_clauses("look"), vec!["look"]);
+        assert!(en.split_clauses(" . , ").is_empty());
+
+        let it = parser(Language::ITALIAN);
+        assert_eq!(
+            it.split_clauses("prendi lampada e poi nord, e"),
+            vec!["prendi lampada", "nord", "e"]
+        );
+
+        let es = parser(Language::SPANISH);
+        assert_eq!(
+            es.split_clauses("toma la lampara y luego norte"),
+            vec!["toma la lampara", "norte"]
+        );
+    }
+
+    #[test]
+    fn parses_object_selections() {
+        let en = parser(Language::ENGLISH);
+        assert_eq!(
+            en.parse("take all").unwrap().selection,
+            Some(Selection::All { except: vec![] })
+        );
+        assert_eq!(
+            en.parse("drop all except the lamp and sword")
+                .unwrap()
+                .selection,
+            Some(Selection::All {
+                except: vec!["lamp".to_string(), "sword".to_string()]
+            })
+        );
+        assert_eq!(
+            en.parse("take sword and the lamp").unwrap().selection,
+            Some(Selection::Items(vec![
+                "sword".to_string(),
+                "lamp".to_string()
+            ]))
+        );
+        assert!(en.parse("take lamp").unwrap().selection.is_none());
+        assert!(en.parse("open all").unwrap().selection.is_none());
+
+        let it = parser(Language::ITALIAN);
+        assert_eq!(
+            it.parse("prendi tutto tranne la spada").unwrap().selection,
+            Some(Selection::All {
+                except: vec!["spada".to_string()]
+            })
+        );
+
+        let es = parser(Language::SPANISH);
+        let cmd = es.parse("toma todo").unwrap();
+        assert!(matches!(cmd.verb, Verb::Take));
+        assert_eq!(cmd.selection, Some(Selection::All { except: vec![] }));
+
+        let cmd = es.parse("poner espada y lampara en caja").unwrap();
+        assert_eq!(
+            cmd.selection,
+            Some(Selection::Items(vec![
+                "espada".to_string(),
+                "lampara".to_string()
+            ]))
+        );
+        assert_eq!(cmd.indirect.as_deref(), Some("caja"));
+    }
+
+    #[test]
+    fn parses_lock_and_unlock_with_a_key() {
+        let cmd = parser(Language::ENGLISH)
+            .parse("unlock the grating with the key")
+            .unwrap();
+        assert!(matches!(cmd.verb, Verb::Unlock));
+        assert_eq!(cmd.direct.as_deref(), Some("grating"));
+        assert_eq!(cmd.preposition, Some(Preposition::With));
+        assert_eq!(cmd.indirect.as_deref(), Some("key"));
+
+        let cmd = parser(Language::ITALIAN)
+            .parse("chiudi a chiave la grata con la chiave")
+            .unwrap();
+        assert!(matches!(cmd.verb, Verb::Lock));
+        assert_eq!(cmd.direct.as_deref(), Some("grata"));
+        assert_eq!(cmd.indirect.as_deref(), Some("chiave"));
+
+        let cmd = parser(Language::SPANISH)
+            .parse("cierra con llave la rejilla")
+            .unwrap();
+        assert!(matches!(cmd.verb, Verb::Lock));
+        assert_eq!(cmd.direct.as_deref(), Some("rejilla"));
+    }
+
+    #[test]
+    fn parses_tying_and_climbing_in_every_language() {
+        let cmd = parser(Language::ENGLISH)
+            .parse("tie the rope to the railing")
+            .unwrap();
+        assert!(matches!(cmd.verb, Verb::Tie));
+        assert_eq!(cmd.direct.as_deref(), Some("rope"));
+        assert_eq!(cmd.preposition, Some(Preposition::To));
+        assert_eq!(cmd.indirect.as_deref(), Some("railing"));
+
+        let cmd = parser(Language::ITALIAN)
+            .parse("lega la corda alla ringhiera")
+            .unwrap();
+        assert!(matches!(cmd.verb, Verb::Tie));
+        assert_eq!(cmd.direct.as_deref(), Some("corda"));
+        assert_eq!(cmd.indirect.as_deref(), Some("ringhiera"));
+
+        let cmd = parser(Language::SPANISH)
+            .parse("ata la cuerda a la barandilla")
+            .unwrap();
+        assert!(matches!(cmd.verb, Verb::Tie));
+        assert_eq!(cmd.indirect.as_deref(), Some("barandilla"));
+
+        let cmd = parser(Language::SPANISH).parse("trepa el árbol").unwrap();
+        assert!(matches!(cmd.verb, Verb::Climb));
+        assert_eq!(cmd.direct.as_deref(), Some("árbol"));
+
+        let cmd = parser(Language::ITALIAN).parse("bevi l'acqua").unwrap();
+        assert!(matches!(cmd.verb, Verb::Drink));
+    }
+
+    #[test]
+    fn parses_lighting_and_putting_out() {
+        let en = parser(Language::ENGLISH);
+        for (input, light) in [
+            ("turn on the lamp", true),
+            ("turn the lamp off", false),
+            ("switch off lamp", false),
+            ("blow out the candles", false),
+            ("light candles with match", true),
+        ] {
+            let cmd = en.parse(input).unwrap();
+            assert_eq!(matches!(cmd.verb, Verb::Light), light, "{input}");
+            assert_eq!(matches!(cmd.verb, Verb::Extinguish), !light, "{input}");
+        }
+        let cmd = en.parse("put the sword on the table").unwrap();
+        assert!(matches!(cmd.verb, Verb::Put));
+
+        let cmd = parser(Language::ITALIAN)
+            .parse("accendi le candele col fiammifero")
+            .unwrap();
+        assert!(matches!(cmd.verb, Verb::Light));
+        assert_eq!(cmd.direct.as_deref(), Some("candele"));
+        assert_eq!(cmd.indirect.as_deref(), Some("fiammifero"));
+
+        let cmd = parser(Language::SPANISH)
+            .parse("apaga la linterna")
+            .unwrap();
+        assert!(matches!(cmd.verb, Verb::Extinguish));
+        assert_eq!(cmd.direct.as_deref(), Some("linterna"));
+    }
+
+    #[test]
+    fn pronouns_resolve_against_context() {
+        let mut context = ParserContext::default();
+        let en = parser(Language::ENGLISH);
+        assert!(
+            en.parse_in_context("read it", &context)
+                .unwrap()
+                .direct
+                .is_none()
+        );
+
+        context.remember(&["mailbox".to_string()]);
+        let cmd = en.parse_in_context("read it", &context).unwrap();
+        assert!(matches!(cmd.verb, Verb::Read));
+        assert_eq!(cmd.direct.as_deref(), Some("mailbox"));
+
+        context.remember(&["sword".to_string(), "lamp".to_string()]);
+        let cmd = en.parse_in_context("drop them", &context).unwrap();
+        assert_eq!(
+            cmd.selection,
+            Some(Selection::Items(vec![
+                "sword".to_string(),
+                "lamp".to_string()
+            ]))
+        );
+
+        context.remember(&["sword".to_string()]);
+        let it = parser(Language::ITALIAN);
+        let cmd = it.parse_in_context("esaminala", &context).unwrap();
+        assert!(matches!(cmd.verb, Verb::Examine));
+        assert_eq!(cmd.direct.as_deref(), Some("sword"));
+
+        let cmd = it
+            .parse_in_context("mettila nella cassa", &context)
+            .unwrap();
+        assert!(matches!(cmd.verb, Verb::Put));
+        assert_eq!(cmd.direct.as_deref(), Some("sword"));
+        assert_eq!(cmd.indirect.as_deref(), Some("cassa"));
+
+        let cmd = it.parse_in_context("prendi la spada", &context).unwrap();
+        assert_eq!(cmd.direct.as_deref(), Some("spada"));
+
+        context.remember(&["lamp".to_string()]);
+        let es = parser(Language::SPANISH);
+        let cmd = es.parse_in_context("enciéndela", &context).unwrap();
+        assert!(matches!(cmd.verb, Verb::Light));
+        assert_eq!(cmd.direct.as_deref(), Some("lamp"));
+
+        let cmd = es.parse_in_context("cógelo", &context).unwrap();
+        assert!(matches!(cmd.verb, Verb::Take));
+        assert!(matches!(es.parse("sale").unwrap().verb, Verb::Unknown(_)));
     }
 }
@@ -0,0 +1,53 @@
+use super::{Preposition, Verb};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Everything the parser knows about one language, read from the `parser`
+/// section of its translation pack. All words are lowercase.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Words {
+    /// Spellings of each verb, directions and abbreviations included.
+    pub verbs: HashMap<Verb, Vec<String>>,
+    pub prepositions: HashMap<Preposition, Vec<String>>,
+    /// Dropped from the front of noun phrases.
+    pub articles: Vec<String>,
+    /// Stand for the last thing referred to (`it`, `lo`).
+    pub pronouns: Vec<String>,
+    /// Pronouns that attach to imperatives (`prendila`, `cógelo`); longer
+    /// ones first so `los` is tried before `lo`.
+    pub enclitics: Vec<String>,
+    /// Start a new clause (`then`, `poi`).
+    pub sequencers: Vec<String>,
+    /// Join list items (`and`); one left before a sequencer is dropped.
+    pub joiners: Vec<String>,
+    /// `all` in `take all`.
+    pub all: Vec<String>,
+    /// `except` in `drop all except lamp`.
+    pub except: Vec<String>,
+    /// May stand between `go` and a direction (`go to the north`).
+    pub go_fillers: Vec<String>,
+    /// Turns `close` into `lock` when it follows the verb (`chiudi a chiave`).
+    pub lock_phrase: Vec<String>,
+    /// Verbs made by a particle: `turn` + `on` is light, `blow` + `out` is
+    /// extinguish. The particle may come right after the verb or last.
+    pub phrasal: HashMap<String, HashMap<String, Verb>>,
+    /// Marks after which a word is split, for elisions (`l'acqua`).
+    pub elisions: Vec<String>,
+    /// Answers that leave the game, even at the death and victory prompts.
+    pub quit: Vec<String>,
+    /// Answers that start over after death or victory.
+    pub restart: Vec<String>,
+    /// Name a saved game and may precede its slot name (`delete save foo`).
+    pub save_nouns: Vec<String>,
+}
+
+impl Words {
+    pub fn is_quit(&self, input: &str) -> bool {
+        self.quit.iter().any(|word| word == input)
+    }
+
+    pub fn is_restart(&self, input: &str) -> bool {
+        self.restart.iter().any(|word| word == input)
+    }
+}